@@ -0,0 +1,138 @@
+//! The declarative job file format for `omf-cli run`: a list of inputs, a list of transforms
+//! applied in order, and where to write each result. There is no separate filter section;
+//! filtering is done by transforms such as `drop_attributes`.
+//!
+//! Written as YAML or JSON (detected from the job file's extension; `.yml`/`.yaml` parse as
+//! YAML, anything else as JSON), so data managers can check a job file into version control and
+//! re-run the same conversion pipeline without writing Rust or scripting against the API.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::report::{JobReport, TaskOutcome};
+use crate::transform::Transform;
+
+/// One `omf-cli run` job: a batch of independent input/output conversions, each put through the
+/// same list of transforms.
+#[derive(Debug, Deserialize)]
+pub struct Job {
+    /// `.omf` files to process.
+    pub inputs: Vec<PathBuf>,
+    /// Transforms applied to every input, in order. Empty means "just copy through", useful for
+    /// a job that only exists to validate a batch of files.
+    #[serde(default)]
+    pub transforms: Vec<Transform>,
+    /// Where to write each input's result. Must have the same length as `inputs`, matched by
+    /// position; keeping the two lists explicit (rather than deriving output names from input
+    /// names) avoids surprising collisions when many inputs share a directory.
+    pub outputs: Vec<PathBuf>,
+    /// Number of inputs processed concurrently. Defaults to the number of available CPUs.
+    #[serde(default)]
+    pub threads: Option<usize>,
+    /// Reports each input's peak memory usage (see [`omf::memory`]) alongside its outcome.
+    /// Requires the crate's `mem-profile` feature and its `TrackingAllocator` installed as the
+    /// process's global allocator to report anything but zero. Forces `threads` to `1`: peak
+    /// memory is tracked with one process-wide counter, so measuring inputs concurrently would
+    /// attribute one input's allocations to another.
+    #[serde(default)]
+    pub report_memory: bool,
+}
+
+impl Job {
+    /// Loads and parses a job file, choosing YAML or JSON based on `path`'s extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| format!("reading {}: {e}", path.display()))?;
+        let is_yaml = matches!(path.extension().and_then(|e| e.to_str()), Some("yml" | "yaml"));
+        let job: Job = if is_yaml {
+            serde_yaml::from_str(&text).map_err(|e| format!("parsing {}: {e}", path.display()))?
+        } else {
+            serde_json::from_str(&text).map_err(|e| format!("parsing {}: {e}", path.display()))?
+        };
+        if job.inputs.len() != job.outputs.len() {
+            return Err(format!(
+                "job file has {} inputs but {} outputs; they must match one-to-one by position",
+                job.inputs.len(),
+                job.outputs.len()
+            ));
+        }
+        Ok(job)
+    }
+
+    /// Runs every input/output pair through `transforms`, in parallel up to `threads`, and
+    /// collects the outcome of each into a [`JobReport`].
+    pub fn run(&self) -> Result<JobReport, String> {
+        let threads = if self.report_memory { 1 } else { self.threads.unwrap_or(0) };
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| format!("building thread pool: {e}"))?;
+        let outcomes = pool.install(|| {
+            use rayon::prelude::*;
+            self.inputs
+                .par_iter()
+                .zip(self.outputs.par_iter())
+                .map(|(input, output)| self.run_one(input, output))
+                .collect::<Vec<_>>()
+        });
+        Ok(JobReport { outcomes })
+    }
+
+    fn run_one(&self, input: &Path, output: &Path) -> TaskOutcome {
+        if self.report_memory {
+            return self.run_one_with_memory_report(input, output);
+        }
+        match self.convert(input, output) {
+            Ok(()) => TaskOutcome::success(input, output, None),
+            Err(error) => TaskOutcome::failure(input, output, error),
+        }
+    }
+
+    #[cfg(feature = "mem-profile")]
+    fn run_one_with_memory_report(&self, input: &Path, output: &Path) -> TaskOutcome {
+        let (result, report) = omf::memory::measure(|| self.convert(input, output));
+        match result {
+            Ok(()) => TaskOutcome::success(input, output, Some(report.peak_bytes)),
+            Err(error) => TaskOutcome::failure(input, output, error),
+        }
+    }
+
+    /// Falls back to an unmeasured conversion with no peak-bytes figure: without `mem-profile`,
+    /// [`omf::memory::measure`] doesn't exist to call.
+    #[cfg(not(feature = "mem-profile"))]
+    fn run_one_with_memory_report(&self, input: &Path, output: &Path) -> TaskOutcome {
+        match self.convert(input, output) {
+            Ok(()) => TaskOutcome::success(input, output, None),
+            Err(error) => TaskOutcome::failure(input, output, error),
+        }
+    }
+
+    fn convert(&self, input: &Path, output: &Path) -> Result<(), String> {
+        if let Some(Transform::Recompress { method, level }) =
+            self.transforms.iter().find(|t| matches!(t, Transform::Recompress { .. }))
+        {
+            // Recompression rewrites every member of the archive, so it must run as its own pass
+            // over the raw file rather than through `Writer::edit`, which otherwise carries
+            // members over using whatever compression they already had.
+            let source = std::fs::File::open(input).map_err(|e| format!("opening {}: {e}", input.display()))?;
+            let target = std::fs::File::create(output).map_err(|e| format!("creating {}: {e}", output.display()))?;
+            omf::file::recompress(source, target, *method, *level).map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+
+        let file = std::fs::File::open(input).map_err(|e| format!("opening {}: {e}", input.display()))?;
+        let mut reader = omf::file::Reader::new(file).map_err(|e| e.to_string())?;
+        let mut project = reader.project().map_err(|e| e.to_string())?;
+        // `Writer::edit` carries every array over from `input` untouched; transforms that need to
+        // change array contents (e.g. dropping textures) do so by editing `project` before
+        // `finish` so the corresponding array is simply never referenced, not by re-encoding
+        // arrays a transform doesn't otherwise touch.
+        let mut writer = omf::file::Writer::edit(input, output).map_err(|e| e.to_string())?;
+        for transform in &self.transforms {
+            transform.apply(&mut project, &mut reader, &mut writer).map_err(|e| e.to_string())?;
+        }
+        writer.finish(&project).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
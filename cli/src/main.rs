@@ -0,0 +1,31 @@
+//! `omf-cli`: a small command-line front end for batch `.omf` conversion jobs.
+//!
+//! Usage: `omf-cli run <job-file.yaml|job-file.json>`. See [`job`] for the job file schema.
+
+mod job;
+mod report;
+mod transform;
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    let (Some(command), Some(job_path)) = (args.get(1), args.get(2)) else {
+        eprintln!("usage: omf-cli run <job-file.yaml|job-file.json>");
+        return ExitCode::FAILURE;
+    };
+    if command != "run" {
+        eprintln!("unknown command \"{command}\"; the only supported command is \"run\"");
+        return ExitCode::FAILURE;
+    }
+    match job::Job::load(job_path).and_then(|job| job.run()) {
+        Ok(report) => {
+            println!("{}", report.to_text());
+            if report.failure_count() > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        }
+        Err(error) => {
+            eprintln!("omf-cli: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! The summary report printed after an `omf-cli run`.
+
+use std::path::{Path, PathBuf};
+
+/// The outcome of converting a single input/output pair.
+pub struct TaskOutcome {
+    pub input: PathBuf,
+    pub output: PathBuf,
+    pub error: Option<String>,
+    /// Peak bytes allocated while converting this input, if the job requested memory reporting.
+    /// See [`omf::memory`]; `None` means reporting wasn't requested, not that usage was zero.
+    pub peak_bytes: Option<u64>,
+}
+
+impl TaskOutcome {
+    pub fn success(input: &Path, output: &Path, peak_bytes: Option<u64>) -> Self {
+        Self { input: input.to_path_buf(), output: output.to_path_buf(), error: None, peak_bytes }
+    }
+
+    pub fn failure(input: &Path, output: &Path, error: String) -> Self {
+        Self { input: input.to_path_buf(), output: output.to_path_buf(), error: Some(error), peak_bytes: None }
+    }
+}
+
+/// The result of one `omf-cli run` job: every input's outcome, in the order the job file listed
+/// them (not necessarily the order they finished, since they run in parallel).
+pub struct JobReport {
+    pub outcomes: Vec<TaskOutcome>,
+}
+
+impl JobReport {
+    pub fn failure_count(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.error.is_some()).count()
+    }
+
+    /// A human-readable summary: one line per input, then a totals line.
+    pub fn to_text(&self) -> String {
+        let mut lines = Vec::new();
+        for outcome in &self.outcomes {
+            match &outcome.error {
+                None => match outcome.peak_bytes {
+                    Some(peak_bytes) => lines.push(format!(
+                        "ok   {} -> {} (peak {peak_bytes} bytes)",
+                        outcome.input.display(),
+                        outcome.output.display()
+                    )),
+                    None => lines.push(format!("ok   {} -> {}", outcome.input.display(), outcome.output.display())),
+                },
+                Some(error) => lines.push(format!("FAIL {} -> {}: {error}", outcome.input.display(), outcome.output.display())),
+            }
+        }
+        let failures = self.failure_count();
+        lines.push(format!("{} succeeded, {failures} failed", self.outcomes.len() - failures));
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,242 @@
+//! Transforms an `omf-cli` job file can apply to a project as it's converted, in the order
+//! listed under the job's `transforms` key.
+
+use std::io::{Read, Seek, Write};
+
+use serde::Deserialize;
+
+use omf::file::{CompressionMethod, Reader, Writer};
+use omf::geometry::Geometry;
+use omf::Project;
+
+/// One conversion step. Applied to `project` in place; a transform that needs to change array
+/// contents does so by writing new arrays through `writer` and repointing the relevant reference,
+/// leaving every array it doesn't touch exactly as [`Writer::edit`] carried it over.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Transform {
+    /// Shifts every element's origin by `offset`, e.g. to move a project from a mine grid onto a
+    /// local site grid without re-encoding every vertex array.
+    RebaseOrigin { offset: [f64; 3] },
+    /// Drops every attribute whose data is `Texcoord`, e.g. for a file destined for a viewer
+    /// that doesn't render textures. Only the attribute entries are removed: the now-unreferenced
+    /// coordinate arrays stay in the output file, since [`Writer::edit`] has already carried
+    /// every array over, so the file does not get smaller.
+    DropTextures,
+    /// Drops every attribute named in `names`, on every element, e.g. to remove a QA-only column
+    /// before shipping a file externally. The now-unreferenced value arrays stay in the output
+    /// file, same as [`Transform::DropTextures`].
+    DropAttributes { names: Vec<String> },
+    /// Renames the element named `from` to `to`. No-op if no element is named `from`.
+    RenameElement { from: String, to: String },
+    /// Sets the display colors for a Category attribute (see
+    /// [`omf::attribute::set_category_colors`]), one `[r, g, b, a]` color per entry in `colors`,
+    /// matched by position against the attribute's `names`. No-op if no attribute named
+    /// `attribute` exists on the element named `element`, or it isn't a Category attribute.
+    ReassignCategoryColors { element: String, attribute: String, colors: Vec<[u8; 4]> },
+    /// Applies an affine transform (rotation/scale via `matrix`, then `translation`) to every
+    /// element. Geometry addressed purely through an origin and axis directions (`BlockModel`,
+    /// `GridSurface`) is transformed by rotating those, with no array left to regenerate; geometry
+    /// with a raw world-space vertex array (`PointSet`, `LineSet`, `Surface`) has that array read
+    /// back through `reader`, rotated/scaled, and rewritten under a new name through `writer`, a
+    /// datum shift's whole reason for streaming rather than requiring a 3rd-party tool to decode
+    /// and re-encode the entire project.
+    Affine { matrix: [[f64; 3]; 3], translation: [f64; 3] },
+    /// Rewrites the whole file at a different zip compression level via
+    /// [`omf::file::recompress`]. Must be the job's only transform: recompression streams every
+    /// archive member as opaque bytes, so it can't be combined with a transform that also needs
+    /// to edit `project.json` in the same pass.
+    Recompress {
+        #[serde(with = "compression_method_serde")]
+        method: CompressionMethod,
+        #[serde(default)]
+        level: Option<i64>,
+    },
+}
+
+mod compression_method_serde {
+    use super::CompressionMethod;
+    use serde::{Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CompressionMethod, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.as_str() {
+            "stored" => Ok(CompressionMethod::Stored),
+            "deflated" => Ok(CompressionMethod::Deflated),
+            "bzip2" => Ok(CompressionMethod::Bzip2),
+            "zstd" => Ok(CompressionMethod::Zstd),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown compression method \"{other}\"; expected one of \
+                 \"stored\", \"deflated\", \"bzip2\", \"zstd\""
+            ))),
+        }
+    }
+}
+
+impl Transform {
+    pub fn apply<R, W>(&self, project: &mut Project, reader: &mut Reader<R>, writer: &mut Writer<W>) -> Result<(), String>
+    where
+        R: Read + Seek,
+        W: Write + Seek,
+    {
+        match self {
+            Transform::RebaseOrigin { offset } => {
+                for element in &mut project.elements {
+                    rebase_origin(&mut element.geometry, *offset);
+                }
+                Ok(())
+            }
+            Transform::DropTextures => {
+                for element in &mut project.elements {
+                    element.attributes.retain(|a| !matches!(a.data, omf::AttributeData::Texcoord { .. }));
+                }
+                Ok(())
+            }
+            Transform::DropAttributes { names } => {
+                for element in &mut project.elements {
+                    element.attributes.retain(|a| !names.contains(&a.name));
+                }
+                Ok(())
+            }
+            Transform::RenameElement { from, to } => {
+                for element in &mut project.elements {
+                    if &element.name == from {
+                        element.name = to.clone();
+                    }
+                }
+                Ok(())
+            }
+            Transform::ReassignCategoryColors { element, attribute, colors } => {
+                for e in &mut project.elements {
+                    if &e.name != element {
+                        continue;
+                    }
+                    for a in &mut e.attributes {
+                        if a.name == *attribute && matches!(a.data, omf::AttributeData::Category { .. }) {
+                            omf::attribute::set_category_colors(a, colors.clone());
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Transform::Affine { matrix, translation } => {
+                for element in &mut project.elements {
+                    apply_affine(&mut element.geometry, matrix, translation, reader, writer)?;
+                }
+                Ok(())
+            }
+            // Handled directly by `Job::convert`, which runs `omf::file::recompress` over the raw
+            // file instead of going through the per-element `Project` edits the other transforms
+            // use.
+            Transform::Recompress { .. } => Ok(()),
+        }
+    }
+}
+
+/// Applies `matrix` (rotation/scale) then `translation` to a position.
+fn affine_point(matrix: &[[f64; 3]; 3], translation: &[f64; 3], point: [f64; 3]) -> [f64; 3] {
+    let mut result = *translation;
+    for row in 0..3 {
+        for col in 0..3 {
+            result[row] += matrix[row][col] * point[col];
+        }
+    }
+    result
+}
+
+/// Applies `matrix` only (no translation) to a direction, e.g. an axis vector, which shouldn't
+/// move just because the geometry's origin does.
+fn affine_direction(matrix: &[[f64; 3]; 3], direction: [f64; 3]) -> [f64; 3] {
+    affine_point(matrix, &[0.0; 3], direction)
+}
+
+fn apply_affine<R, W>(
+    geometry: &mut Geometry,
+    matrix: &[[f64; 3]; 3],
+    translation: &[f64; 3],
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+) -> Result<(), String>
+where
+    R: Read + Seek,
+    W: Write + Seek,
+{
+    match geometry {
+        Geometry::PointSet(g) => {
+            affine_vertices(&mut g.vertices, matrix, reader, writer)?;
+            g.origin = affine_point(matrix, translation, g.origin);
+        }
+        Geometry::LineSet(g) => {
+            affine_vertices(&mut g.vertices, matrix, reader, writer)?;
+            g.origin = affine_point(matrix, translation, g.origin);
+        }
+        Geometry::Surface(g) => {
+            affine_vertices(&mut g.vertices, matrix, reader, writer)?;
+            g.origin = affine_point(matrix, translation, g.origin);
+        }
+        Geometry::BlockModel(g) => {
+            g.origin = affine_point(matrix, translation, g.origin);
+            for axis in &mut g.axes {
+                *axis = affine_direction(matrix, *axis);
+            }
+        }
+        Geometry::GridSurface(g) => {
+            g.origin = affine_point(matrix, translation, g.origin);
+            g.axis_u = affine_direction(matrix, g.axis_u);
+            g.axis_v = affine_direction(matrix, g.axis_v);
+        }
+        Geometry::Composite(composite) => {
+            for child in &mut composite.elements {
+                apply_affine(&mut child.geometry, matrix, translation, reader, writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the `[f64; 3]` array named `*vertices_name`, rotates/scales every entry by `matrix`
+/// (translation is applied separately, to the geometry's `origin`, so it's shared across every
+/// vertex without inflating the array), and rewrites the result under a fresh array name that
+/// `*vertices_name` is then repointed to. The original array is left in place, unreferenced, same
+/// as [`Transform::DropTextures`].
+fn affine_vertices<R, W>(
+    vertices_name: &mut String,
+    matrix: &[[f64; 3]; 3],
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+) -> Result<(), String>
+where
+    R: Read + Seek,
+    W: Write + Seek,
+{
+    let vertices = reader.read_array_f64x3(vertices_name).map_err(|e| e.to_string())?;
+    let transformed: Vec<[f64; 3]> = vertices.iter().map(|&v| affine_direction(matrix, v)).collect();
+    let mut new_name = format!("{vertices_name}-affine");
+    let mut suffix = 1;
+    while writer.has_array(&new_name) {
+        new_name = format!("{vertices_name}-affine-{suffix}");
+        suffix += 1;
+    }
+    writer.write_array(&new_name, bytemuck::cast_slice(&transformed)).map_err(|e| e.to_string())?;
+    *vertices_name = new_name;
+    Ok(())
+}
+
+fn rebase_origin(geometry: &mut Geometry, offset: [f64; 3]) {
+    let origin = match geometry {
+        Geometry::PointSet(g) => &mut g.origin,
+        Geometry::LineSet(g) => &mut g.origin,
+        Geometry::Surface(g) => &mut g.origin,
+        Geometry::BlockModel(g) => &mut g.origin,
+        Geometry::GridSurface(g) => &mut g.origin,
+        Geometry::Composite(composite) => {
+            for child in &mut composite.elements {
+                rebase_origin(&mut child.geometry, offset);
+            }
+            return;
+        }
+    };
+    for axis in 0..3 {
+        origin[axis] += offset[axis];
+    }
+}
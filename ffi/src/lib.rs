@@ -0,0 +1,439 @@
+//! C API wrapper around the `omf` crate. Every function is `extern "C"` and takes/returns raw
+//! pointers or opaque handles; see `include/omf.h` for the corresponding C declarations.
+//!
+//! Deliberately narrow in scope: there's no C `Reader`, `Writer`, or `Project` handle, only the
+//! composite-builder utilities below (for constructing nested composite elements a level at a
+//! time, including from `omf_composite_builder_add_composite_child`), `omf_error_code_name`, and
+//! `omf_days_since_epoch_to_ymd`.
+//! A C consumer that needs to read or write a whole `.omf` file uses `omf-python` or the `cli`
+//! binary instead; this crate exists for embedders (e.g. a plugin host) that only need to build
+//! or inspect a composite element in isolation.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use omf::attribute::days_since_epoch_to_ymd;
+use omf::geometry::{Composite, Geometry};
+use omf::project::Element;
+
+/// Converts `days` since the Unix epoch into a `(year, month, day)` triple, writing them through
+/// the output pointers. Returns `false` (leaving the outputs unwritten) if `days` is out of
+/// range to represent as an `i32` year, instead of silently overflowing.
+///
+/// # Safety
+/// `year`, `month`, and `day` must be valid, non-null, writable pointers.
+#[no_mangle]
+pub unsafe extern "C" fn omf_days_since_epoch_to_ymd(
+    days: i64,
+    year: *mut i32,
+    month: *mut u32,
+    day: *mut u32,
+) -> bool {
+    match days_since_epoch_to_ymd(days) {
+        Some((y, m, d)) => {
+            *year = y;
+            *month = m;
+            *day = d;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Opaque handle to an in-progress composite element being built up from C.
+pub struct OmfCompositeBuilder {
+    elements: Vec<Element>,
+}
+
+/// Creates a new, empty composite element builder. Free it with `omf_composite_builder_free`,
+/// or consume it with `omf_composite_builder_finish`.
+#[no_mangle]
+pub extern "C" fn omf_composite_builder_new() -> *mut OmfCompositeBuilder {
+    Box::into_raw(Box::new(OmfCompositeBuilder { elements: Vec::new() }))
+}
+
+/// Frees a composite builder without finishing it.
+///
+/// # Safety
+/// `builder` must be a pointer returned by `omf_composite_builder_new` and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_free(builder: *mut OmfCompositeBuilder) {
+    if !builder.is_null() {
+        drop(Box::from_raw(builder));
+    }
+}
+
+/// Adds a child point-set element to the composite, taking ownership of the vertex array
+/// referenced by `vertices_array_name`.
+///
+/// # Safety
+/// `builder` and `name`/`vertices_array_name` must be valid, non-null pointers; the C strings
+/// must be NUL-terminated and UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_add_point_set(
+    builder: *mut OmfCompositeBuilder,
+    name: *const c_char,
+    vertices_array_name: *const c_char,
+) -> bool {
+    let Some(builder) = builder.as_mut() else { return false };
+    let (Ok(name), Ok(vertices)) =
+        (CStr::from_ptr(name).to_str(), CStr::from_ptr(vertices_array_name).to_str())
+    else {
+        return false;
+    };
+    builder.elements.push(Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        description: String::new(),
+        geometry: Geometry::PointSet(omf::geometry::PointSet {
+            vertices: vertices.to_string(),
+            origin: [0.0; 3],
+        }),
+        attributes: Vec::new(),
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata: Default::default(),
+    });
+    true
+}
+
+/// Consumes the builder, producing an opaque handle to the finished composite element ready to
+/// be attached to a project. Always frees `builder`, even on failure.
+///
+/// # Safety
+/// `builder` must be a pointer returned by `omf_composite_builder_new` and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_finish(
+    builder: *mut OmfCompositeBuilder,
+) -> *mut Composite {
+    if builder.is_null() {
+        return std::ptr::null_mut();
+    }
+    let builder = Box::from_raw(builder);
+    Box::into_raw(Box::new(Composite { elements: builder.elements }))
+}
+
+/// Frees a composite element handle returned by `omf_composite_builder_finish`.
+///
+/// # Safety
+/// `composite` must be a pointer returned by `omf_composite_builder_finish` and not already
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_free(composite: *mut Composite) {
+    if !composite.is_null() {
+        drop(Box::from_raw(composite));
+    }
+}
+
+/// Adds a child composite element to `builder`, taking ownership of `child` (a handle previously
+/// returned by `omf_composite_builder_finish`), so C consumers can build up arbitrarily nested
+/// composites one level at a time instead of needing a single call that takes the whole tree at
+/// once. Always frees `child`, even on failure.
+///
+/// # Safety
+/// `builder`, `name`, and `child` must be valid, non-null pointers; `child` must not already be
+/// freed; `name` must be NUL-terminated and UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_add_composite_child(
+    builder: *mut OmfCompositeBuilder,
+    name: *const c_char,
+    child: *mut Composite,
+) -> bool {
+    let child = if child.is_null() { None } else { Some(Box::from_raw(child)) };
+    let Some(builder) = builder.as_mut() else { return false };
+    let Ok(name) = CStr::from_ptr(name).to_str() else { return false };
+    let Some(child) = child else { return false };
+    builder.elements.push(Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        description: String::new(),
+        geometry: Geometry::Composite(*child),
+        attributes: Vec::new(),
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata: Default::default(),
+    });
+    true
+}
+
+/// The number of child elements in a finished composite handle, e.g. one produced by
+/// `omf_composite_builder_finish` or received from another part of a C program that built one.
+///
+/// # Safety
+/// `composite` must be a valid, non-null pointer returned by `omf_composite_builder_finish` and
+/// not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_child_count(composite: *const Composite) -> usize {
+    let Some(composite) = composite.as_ref() else { return 0 };
+    composite.elements.len()
+}
+
+/// The name of the child element at `index` in `composite`, returned as a newly allocated
+/// NUL-terminated string the caller must free with `omf_string_free`, or null if `index` is out
+/// of range. Together with `omf_composite_child_count`, lets a C consumer walk a nested composite
+/// without needing a full C `Reader`/`Project` handle (this crate deliberately doesn't have one;
+/// see the module doc).
+///
+/// # Safety
+/// `composite` must be a valid, non-null pointer returned by `omf_composite_builder_finish` and
+/// not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_child_name(composite: *const Composite, index: usize) -> *mut c_char {
+    let Some(composite) = composite.as_ref() else { return std::ptr::null_mut() };
+    let Some(element) = composite.elements.get(index) else { return std::ptr::null_mut() };
+    match std::ffi::CString::new(element.name.as_str()) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// True if the child element at `index` in `composite` is itself a composite (as opposed to a
+/// point set, line set, or surface), i.e. whether recursing into it with
+/// `omf_composite_builder_add_composite_child`'s counterpart on the read side would make sense.
+/// Returns `false` if `index` is out of range. There's no accessor to pull the nested `Composite`
+/// back out as its own handle, since ownership of a child's geometry belongs to its parent once
+/// added; a C consumer that needs to keep traversing should track the tree it built itself.
+///
+/// # Safety
+/// `composite` must be a valid, non-null pointer returned by `omf_composite_builder_finish` and
+/// not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_child_is_composite(composite: *const Composite, index: usize) -> bool {
+    let Some(composite) = composite.as_ref() else { return false };
+    let Some(element) = composite.elements.get(index) else { return false };
+    matches!(element.geometry, Geometry::Composite(_))
+}
+
+/// Sets an arbitrary named field (e.g. `"uid"`, `"crs"`, a display hint) on the element at
+/// `element_index` within `builder`. Fields set this way round-trip through the element's
+/// `metadata` map under their own name, so a newly introduced core field doesn't require a
+/// breaking change to a fixed C `Element` struct. Returns `false` if `element_index` is out of
+/// range or the strings aren't valid UTF-8.
+///
+/// # Safety
+/// `builder`, `field_name`, and `value` must be valid, non-null pointers; the C strings must be
+/// NUL-terminated and UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_set_element_field_string(
+    builder: *mut OmfCompositeBuilder,
+    element_index: usize,
+    field_name: *const c_char,
+    value: *const c_char,
+) -> bool {
+    let Some(builder) = builder.as_mut() else { return false };
+    let Some(element) = builder.elements.get_mut(element_index) else { return false };
+    let (Ok(field_name), Ok(value)) =
+        (CStr::from_ptr(field_name).to_str(), CStr::from_ptr(value).to_str())
+    else {
+        return false;
+    };
+    element.metadata.insert(field_name.to_string(), serde_json::Value::String(value.to_string()));
+    true
+}
+
+/// Reads a field previously set with `omf_composite_builder_set_element_field_string`, returning
+/// a newly allocated NUL-terminated string the caller must free with `omf_string_free`, or null
+/// if `element_index` is out of range, `field_name` isn't set, or it isn't a string field.
+///
+/// # Safety
+/// `builder` and `field_name` must be valid, non-null pointers; `field_name` must be
+/// NUL-terminated and UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn omf_composite_builder_get_element_field_string(
+    builder: *const OmfCompositeBuilder,
+    element_index: usize,
+    field_name: *const c_char,
+) -> *mut c_char {
+    let Some(builder) = builder.as_ref() else { return std::ptr::null_mut() };
+    let Some(element) = builder.elements.get(element_index) else { return std::ptr::null_mut() };
+    let Ok(field_name) = CStr::from_ptr(field_name).to_str() else { return std::ptr::null_mut() };
+    let Some(value) = element.metadata.get(field_name).and_then(|v| v.as_str()) else {
+        return std::ptr::null_mut();
+    };
+    match std::ffi::CString::new(value) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Frees a string returned by `omf_composite_child_name`,
+/// `omf_composite_builder_get_element_field_string`, or `omf_error_code_name`.
+///
+/// # Safety
+/// `s` must be a pointer returned by one of those functions and not already freed, or null.
+#[no_mangle]
+pub unsafe extern "C" fn omf_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(std::ffi::CString::from_raw(s));
+    }
+}
+
+/// Looks up the short, stable name (e.g. `"limit_exceeded"`) for one of `omf::ErrorCode`'s
+/// numeric values, returned as a newly allocated NUL-terminated string the caller must free with
+/// `omf_string_free`, or null if `code` isn't one of `omf::ErrorCode::ALL`.
+///
+/// This crate's boolean/null-pointer failure signaling doesn't yet carry an error code out of any
+/// individual call, but callers who reach an `omf::ErrorCode` some other way (e.g. today only
+/// `omf-python`, which raises it as part of its exception) can use this to build the same
+/// human-readable conversion table in C without hand-copying it.
+#[no_mangle]
+pub extern "C" fn omf_error_code_name(code: u32) -> *mut c_char {
+    let Some(code) = omf::ErrorCode::ALL.into_iter().find(|c| *c as u32 == code) else {
+        return std::ptr::null_mut();
+    };
+    match std::ffi::CString::new(code.name()) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+
+    fn c_string(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn null_builder_and_composite_pointers_fail_safely_instead_of_crashing() {
+        unsafe {
+            let name = c_string("name");
+            let vertices = c_string("vertices");
+            assert!(!omf_composite_builder_add_point_set(
+                std::ptr::null_mut(),
+                name.as_ptr(),
+                vertices.as_ptr()
+            ));
+
+            assert!(omf_composite_builder_finish(std::ptr::null_mut()).is_null());
+
+            assert_eq!(omf_composite_child_count(std::ptr::null()), 0);
+            assert!(omf_composite_child_name(std::ptr::null(), 0).is_null());
+            assert!(!omf_composite_child_is_composite(std::ptr::null(), 0));
+
+            // Freeing a null pointer must be a no-op, not a crash, since callers can't always
+            // know in advance whether a handle they hold is null (e.g. after a failed build step).
+            omf_composite_builder_free(std::ptr::null_mut());
+            omf_composite_free(std::ptr::null_mut());
+            omf_string_free(std::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn add_composite_child_frees_the_child_even_when_the_builder_is_null() {
+        // `child` must always be freed per its documented contract, even on the failure path
+        // where `builder` is null; if it leaked here, Miri/a sanitized run would catch it.
+        unsafe {
+            let child = omf_composite_builder_finish(omf_composite_builder_new());
+            let name = c_string("child");
+            assert!(!omf_composite_builder_add_composite_child(std::ptr::null_mut(), name.as_ptr(), child));
+        }
+    }
+
+    #[test]
+    fn add_composite_child_fails_on_a_null_child_without_touching_the_builder() {
+        unsafe {
+            let builder = omf_composite_builder_new();
+            let name = c_string("child");
+            assert!(!omf_composite_builder_add_composite_child(builder, name.as_ptr(), std::ptr::null_mut()));
+
+            let composite = omf_composite_builder_finish(builder);
+            assert_eq!(omf_composite_child_count(composite), 0);
+            omf_composite_free(composite);
+        }
+    }
+
+    #[test]
+    fn out_of_range_child_index_is_reported_rather_than_indexed_out_of_bounds() {
+        unsafe {
+            let builder = omf_composite_builder_new();
+            let name = c_string("point set");
+            let vertices = c_string("vertices");
+            assert!(omf_composite_builder_add_point_set(builder, name.as_ptr(), vertices.as_ptr()));
+
+            let composite = omf_composite_builder_finish(builder);
+            assert_eq!(omf_composite_child_count(composite), 1);
+            assert!(omf_composite_child_name(composite, 1).is_null());
+            assert!(!omf_composite_child_is_composite(composite, 1));
+            assert!(!omf_composite_child_is_composite(composite, 0));
+            omf_composite_free(composite);
+        }
+    }
+
+    #[test]
+    fn nested_composite_builds_and_reports_its_child_as_a_composite() {
+        unsafe {
+            let inner_builder = omf_composite_builder_new();
+            let inner_name = c_string("inner point set");
+            let vertices = c_string("vertices");
+            assert!(omf_composite_builder_add_point_set(inner_builder, inner_name.as_ptr(), vertices.as_ptr()));
+            let inner = omf_composite_builder_finish(inner_builder);
+
+            let outer_builder = omf_composite_builder_new();
+            let child_name = c_string("nested");
+            assert!(omf_composite_builder_add_composite_child(outer_builder, child_name.as_ptr(), inner));
+            let outer = omf_composite_builder_finish(outer_builder);
+
+            assert_eq!(omf_composite_child_count(outer), 1);
+            assert!(omf_composite_child_is_composite(outer, 0));
+            let name_ptr = omf_composite_child_name(outer, 0);
+            assert_eq!(CStr::from_ptr(name_ptr).to_str().unwrap(), "nested");
+            omf_string_free(name_ptr);
+            omf_composite_free(outer);
+        }
+    }
+
+    #[test]
+    fn element_field_string_round_trips_and_rejects_out_of_range_or_unset_fields() {
+        unsafe {
+            let builder = omf_composite_builder_new();
+            let name = c_string("point set");
+            let vertices = c_string("vertices");
+            assert!(omf_composite_builder_add_point_set(builder, name.as_ptr(), vertices.as_ptr()));
+
+            let field = c_string("uid");
+            let value = c_string("abc-123");
+            assert!(omf_composite_builder_set_element_field_string(builder, 0, field.as_ptr(), value.as_ptr()));
+            assert!(!omf_composite_builder_set_element_field_string(builder, 1, field.as_ptr(), value.as_ptr()));
+
+            let read_back = omf_composite_builder_get_element_field_string(builder, 0, field.as_ptr());
+            assert_eq!(CStr::from_ptr(read_back).to_str().unwrap(), "abc-123");
+            omf_string_free(read_back);
+
+            let unset = c_string("not_set");
+            assert!(omf_composite_builder_get_element_field_string(builder, 0, unset.as_ptr()).is_null());
+            assert!(omf_composite_builder_get_element_field_string(builder, 1, field.as_ptr()).is_null());
+
+            omf_composite_builder_free(builder);
+        }
+    }
+
+    #[test]
+    fn days_since_epoch_out_of_range_leaves_outputs_untouched_and_returns_false() {
+        unsafe {
+            let (mut year, mut month, mut day) = (0i32, 0u32, 0u32);
+            assert!(!omf_days_since_epoch_to_ymd(i64::MAX, &mut year, &mut month, &mut day));
+            assert_eq!((year, month, day), (0, 0, 0));
+
+            assert!(omf_days_since_epoch_to_ymd(0, &mut year, &mut month, &mut day));
+            assert_eq!((year, month, day), (1970, 1, 1));
+        }
+    }
+
+    #[test]
+    fn error_code_name_is_null_for_an_unrecognized_code() {
+        let unknown = omf::ErrorCode::ALL.iter().map(|c| *c as u32).max().unwrap() + 1;
+        assert!(omf_error_code_name(unknown).is_null());
+
+        let known = omf::ErrorCode::ALL[0];
+        let name_ptr = omf_error_code_name(known as u32);
+        assert!(!name_ptr.is_null());
+        unsafe {
+            assert_eq!(CStr::from_ptr(name_ptr).to_str().unwrap(), known.name());
+            omf_string_free(name_ptr);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use pyo3::prelude::*;
+
+use omf::geometry::Geometry;
+use omf::project::Element;
+
+use crate::geometry::PyComposite;
+
+/// Python wrapper around [`omf::project::Element`].
+///
+/// Composite elements expose their children through [`PyElement::children`] so they can be
+/// traversed the same way as any other element, recursively if the composite is nested.
+#[pyclass(name = "Element")]
+#[derive(Clone)]
+pub struct PyElement {
+    inner: Element,
+}
+
+impl PyElement {
+    pub fn new(inner: Element) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyElement {
+    #[getter]
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    fn description(&self) -> &str {
+        &self.inner.description
+    }
+
+    /// This element's coordinate reference system override (see [`omf::Crs`]) as a `{type, ...}`
+    /// dict, or `None` if it doesn't override [`crate::reader::PyReader::coordinate_reference_system`].
+    #[getter]
+    fn coordinate_reference_system(&self, py: Python<'_>) -> PyResult<PyObject> {
+        crate::reader::crs_to_py(py, self.inner.coordinate_reference_system.as_ref())
+    }
+
+    /// The geometry type name, e.g. `"PointSet"`, `"Surface"`, or `"Composite"`.
+    #[getter]
+    fn geometry_type(&self) -> &'static str {
+        match &self.inner.geometry {
+            Geometry::PointSet(_) => "PointSet",
+            Geometry::LineSet(_) => "LineSet",
+            Geometry::Surface(_) => "Surface",
+            Geometry::Composite(_) => "Composite",
+            Geometry::BlockModel(_) => "BlockModel",
+            Geometry::GridSurface(_) => "GridSurface",
+        }
+    }
+
+    /// For composite elements, a [`PyComposite`] giving access to the child elements. `None` for
+    /// every other geometry type. Shorthand for `self.geometry()`, kept for callers that already
+    /// know they're looking at a composite and don't want to match on the result.
+    fn composite(&self) -> Option<PyComposite> {
+        match &self.inner.geometry {
+            Geometry::Composite(composite) => Some(PyComposite::new(composite.clone())),
+            _ => None,
+        }
+    }
+
+    /// This element's geometry as a Python object, dispatched by `geometry_type`. Currently only
+    /// [`Geometry::Composite`] has a dedicated wrapper ([`PyComposite`]); every other geometry type
+    /// returns `None` here (use `geometry_type` for a cheap type check, and the element's
+    /// attributes/array data via the owning [`crate::reader::PyReader`] instead).
+    fn geometry(&self) -> Option<PyComposite> {
+        self.composite()
+    }
+
+    /// For composite elements, the list of child elements directly. Shorthand for
+    /// `self.geometry().elements()`. `None` for every other geometry type. Traversal is
+    /// recursive for free: each child is itself a [`PyElement`], so calling `children()` again on
+    /// one that's also a composite descends further.
+    fn children(&self) -> Option<Vec<PyElement>> {
+        self.geometry().map(|c| c.elements())
+    }
+
+    /// The names of the attributes attached directly to this element (not its children).
+    fn attribute_names(&self) -> Vec<String> {
+        self.inner.attributes.iter().map(|a| a.name.clone()).collect()
+    }
+}
@@ -0,0 +1,15 @@
+//! Converts [`omf::Error`] into a Python exception that carries the same numeric
+//! [`omf::ErrorCode`] as the C API, so code that talks to both bindings can switch on one
+//! table instead of two.
+
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+
+pyo3::create_exception!(omf, OmfError, PyException);
+
+/// Raises [`OmfError`] with `(message, code)` args, where `code` is the numeric
+/// [`omf::ErrorCode`] value, reachable from Python as `err.args[1]`.
+pub fn to_py_err(error: omf::Error) -> PyErr {
+    let code = error.code() as u32;
+    PyErr::new::<OmfError, _>((error.to_string(), code))
+}
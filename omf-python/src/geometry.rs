@@ -0,0 +1,30 @@
+use pyo3::prelude::*;
+
+use omf::geometry::Composite;
+
+use crate::element::PyElement;
+
+/// Python wrapper around [`omf::geometry::Composite`], exposing its children as [`PyElement`].
+#[pyclass(name = "Composite")]
+#[derive(Clone)]
+pub struct PyComposite {
+    inner: Composite,
+}
+
+impl PyComposite {
+    pub fn new(inner: Composite) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyComposite {
+    /// The nested child elements, in the same order as the file.
+    fn elements(&self) -> Vec<PyElement> {
+        self.inner.elements.iter().cloned().map(PyElement::new).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.elements.len()
+    }
+}
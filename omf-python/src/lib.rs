@@ -0,0 +1,30 @@
+//! PyO3 bindings exposing `omf::file::Reader` and `omf::file::Writer` to Python.
+
+mod element;
+mod error;
+mod geometry;
+mod mask;
+mod pyio;
+mod reader;
+mod writer;
+
+use pyo3::prelude::*;
+
+pub use element::PyElement;
+pub use error::OmfError;
+pub use geometry::PyComposite;
+pub use mask::apply_mask;
+pub use reader::PyReader;
+pub use writer::PyWriter;
+
+/// The `omf` Python extension module.
+#[pymodule]
+fn omf(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyReader>()?;
+    m.add_class::<PyWriter>()?;
+    m.add_class::<PyElement>()?;
+    m.add_class::<PyComposite>()?;
+    m.add_function(pyo3::wrap_pyfunction!(apply_mask, m)?)?;
+    m.add("OmfError", py.get_type_bound::<OmfError>())?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Applies a boolean mask to a float64 array, replacing masked-out entries with `NaN` in a
+/// single vectorized pass instead of the caller looping in Python. `mask[i] == true` keeps
+/// `values[i]`; `false` replaces it with `NaN`.
+#[pyfunction]
+pub fn apply_mask<'py>(
+    py: Python<'py>,
+    values: PyReadonlyArray1<'py, f64>,
+    mask: PyReadonlyArray1<'py, bool>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let values = values.as_slice()?;
+    let mask = mask.as_slice()?;
+    let masked: Vec<f64> = values
+        .iter()
+        .zip(mask.iter())
+        .map(|(&v, &keep)| if keep { v } else { f64::NAN })
+        .collect();
+    Ok(PyArray1::from_vec(py, masked))
+}
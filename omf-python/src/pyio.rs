@@ -0,0 +1,54 @@
+//! Bridges a Python file-like object (anything implementing the `io.RawIOBase`/
+//! `io.BufferedIOBase` `read`/`seek` protocol, e.g. `io.BytesIO` or an `fsspec` stream) to Rust's
+//! `Read`/`Seek`, so [`omf::file::Reader`] can pull bytes from it directly instead of requiring
+//! the whole file to be copied into memory first.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// Wraps a Python object exposing `read(size) -> bytes` and `seek(offset, whence) -> int`.
+pub struct PyIoAdapter {
+    object: Py<PyAny>,
+}
+
+impl PyIoAdapter {
+    pub fn new(object: Py<PyAny>) -> Self {
+        Self { object }
+    }
+}
+
+impl Read for PyIoAdapter {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Python::with_gil(|py| {
+            let chunk = self.object.call_method1(py, "read", (buf.len(),)).map_err(to_io_error)?;
+            let bytes = chunk
+                .downcast_bound::<PyBytes>(py)
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "read() must return bytes"))?;
+            let data = bytes.as_bytes();
+            buf[..data.len()].copy_from_slice(data);
+            Ok(data.len())
+        })
+    }
+}
+
+impl Seek for PyIoAdapter {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        Python::with_gil(|py| {
+            // `whence` follows `io.SEEK_SET`/`io.SEEK_CUR`/`io.SEEK_END`, which Python's `seek`
+            // already uses, so no translation beyond picking the matching integer is needed.
+            let (offset, whence) = match pos {
+                SeekFrom::Start(n) => (n as i64, 0),
+                SeekFrom::Current(n) => (n, 1),
+                SeekFrom::End(n) => (n, 2),
+            };
+            let result = self.object.call_method1(py, "seek", (offset, whence)).map_err(to_io_error)?;
+            result.extract::<i64>(py).map(|offset| offset as u64).map_err(to_io_error)
+        })
+    }
+}
+
+fn to_io_error(error: PyErr) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, error.to_string())
+}
@@ -0,0 +1,190 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use pyo3::exceptions::PyIOError;
+use pyo3::prelude::*;
+
+use omf::file::Reader;
+use omf::Limits;
+
+use crate::element::PyElement;
+use crate::error::to_py_err;
+use crate::pyio::PyIoAdapter;
+
+/// Where a [`PyReader`] pulls its bytes from: a real file, or a Python file-like object bridged
+/// through [`PyIoAdapter`]. Kept as one enum (rather than making [`PyReader`] generic) so the
+/// `#[pyclass]` itself stays a single concrete type, matching how [`crate::writer::WriteTarget`]
+/// handles the analogous choice on the write side.
+enum ReadSource {
+    File(std::fs::File),
+    Python(PyIoAdapter),
+}
+
+impl Read for ReadSource {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            ReadSource::File(file) => file.read(buf),
+            ReadSource::Python(adapter) => adapter.read(buf),
+        }
+    }
+}
+
+impl Seek for ReadSource {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            ReadSource::File(file) => file.seek(pos),
+            ReadSource::Python(adapter) => adapter.seek(pos),
+        }
+    }
+}
+
+/// Python wrapper around [`omf::file::Reader`], opened from a path on disk or from a Python
+/// file-like object via [`PyReader::from_stream`].
+#[pyclass(name = "Reader")]
+pub struct PyReader {
+    inner: Reader<ReadSource>,
+}
+
+#[pymethods]
+impl PyReader {
+    /// Opens `path`. Limits default to [`Limits::recommended`], scaling to the machine's
+    /// available memory instead of a single fixed value that's either too tight for a workstation
+    /// or too loose for an embedded plugin.
+    #[new]
+    fn new(path: &str) -> PyResult<Self> {
+        let file = std::fs::File::open(path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let inner = Reader::with_limits(ReadSource::File(file), Limits::recommended()).map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Opens a reader over any Python object implementing `read(size)`/`seek(offset, whence)`
+    /// (e.g. `io.BytesIO`, an `fsspec` `AbstractBufferedFile`), reading bytes from it on demand
+    /// rather than copying the whole stream into memory up front.
+    #[staticmethod]
+    fn from_stream(stream: Py<PyAny>) -> PyResult<Self> {
+        let inner =
+            Reader::with_limits(ReadSource::Python(PyIoAdapter::new(stream)), Limits::recommended())
+                .map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Reads the project JSON and returns it re-serialized as a Python dict.
+    fn project(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let json = serde_json::to_string(&project).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let json_mod = py.import("json")?;
+        json_mod.call_method1("loads", (json,)).map(|obj| obj.into())
+    }
+
+    /// Returns `project.json`'s contents as a string (see [`omf::file::Reader::project_json`]),
+    /// `pretty`-printed if requested, for callers writing an importer in another language that
+    /// want the raw index document rather than going through `project()`'s dict conversion.
+    #[pyo3(signature = (pretty=false))]
+    fn project_json(&mut self, pretty: bool) -> PyResult<String> {
+        self.inner.project_json(pretty).map_err(to_py_err)
+    }
+
+    /// Reads the project and returns its top-level elements as [`PyElement`], including
+    /// composites, which can then be traversed via `PyElement.children()`.
+    fn elements(&mut self) -> PyResult<Vec<PyElement>> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        Ok(project.elements.into_iter().map(PyElement::new).collect())
+    }
+
+    /// Resolves a category attribute's values through its names in one streaming pass, returning
+    /// a list of strings (or `None` for an out-of-range index) instead of the raw index array, so
+    /// callers don't have to do the `names[index]` join themselves in a Python loop.
+    fn resolve_category(&mut self, py: Python<'_>, element: usize, attribute_name: &str) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let element = project
+            .elements
+            .get(element)
+            .ok_or_else(|| PyIOError::new_err("no such element"))?;
+        let attribute = element
+            .attributes
+            .iter()
+            .find(|a| a.name == attribute_name)
+            .ok_or_else(|| PyIOError::new_err(format!("no such attribute \"{attribute_name}\"")))?;
+        let resolved = self
+            .inner
+            .resolve_category_names(attribute)
+            .map_err(to_py_err)?
+            .ok_or_else(|| PyIOError::new_err(format!("attribute \"{attribute_name}\" is not a category attribute")))?;
+        Ok(pyo3::types::PyList::new_bound(py, resolved.into_iter().map(|name| name.into_py(py))).into())
+    }
+
+    /// Reads the display hint recorded on an attribute (see [`omf::attribute::NumberDisplayHint`])
+    /// as a `{decimal_places, scientific_notation, thousands_separator}` dict, or `None` if it
+    /// isn't set, so viewers can render values like grades and tonnages consistently.
+    fn number_display_hint(&mut self, py: Python<'_>, element: usize, attribute_name: &str) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let element = project
+            .elements
+            .get(element)
+            .ok_or_else(|| PyIOError::new_err("no such element"))?;
+        let attribute = element
+            .attributes
+            .iter()
+            .find(|a| a.name == attribute_name)
+            .ok_or_else(|| PyIOError::new_err(format!("no such attribute \"{attribute_name}\"")))?;
+        match omf::attribute::display_hint_of(attribute) {
+            Some(hint) => {
+                let json = serde_json::to_string(&hint).map_err(|e| PyIOError::new_err(e.to_string()))?;
+                let json_mod = py.import("json")?;
+                json_mod.call_method1("loads", (json,)).map(|obj| obj.into())
+            }
+            None => Ok(py.None()),
+        }
+    }
+
+    /// Searches every metadata map in the project for `key`, returning a list of `{path, value}`
+    /// dicts (see [`omf::project::Project::query_metadata`]), so callers can find e.g. every
+    /// element tagged with a given domain code without writing a recursive search themselves.
+    fn query_metadata(&mut self, py: Python<'_>, key: &str) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let matches = project.query_metadata(key);
+        let json = serde_json::to_string(&matches).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let json_mod = py.import("json")?;
+        json_mod.call_method1("loads", (json,)).map(|obj| obj.into())
+    }
+
+    /// Reads the project's changelog (see `omf::changelog`) as a list of `{timestamp, author,
+    /// description, affected_elements}` dicts, oldest first. Empty if the project has none.
+    fn changelog(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let entries = omf::changelog::entries(&project);
+        let json = serde_json::to_string(&entries).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let json_mod = py.import("json")?;
+        json_mod.call_method1("loads", (json,)).map(|obj| obj.into())
+    }
+
+    /// The dimensions of `element`'s thumbnail image (see
+    /// [`omf::file::Reader::element_thumbnail_dimensions`]), or `None` if it has no thumbnail.
+    /// Read straight from the PNG header, without decoding pixel data.
+    fn element_thumbnail_dimensions(&mut self, element: usize) -> PyResult<Option<(u32, u32)>> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        let element = project.elements.get(element).ok_or_else(|| PyIOError::new_err("no such element"))?;
+        self.inner.element_thumbnail_dimensions(element).map_err(to_py_err)
+    }
+
+    /// The project's coordinate reference system (see [`omf::Crs`]) as a `{type, ...}` dict, or
+    /// `None` if it's unset. Callers that only care about one element's effective CRS should
+    /// check [`PyElement::coordinate_reference_system`] first and fall back to this.
+    fn coordinate_reference_system(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        let project = self.inner.project().map_err(to_py_err)?;
+        crs_to_py(py, project.coordinate_reference_system.as_ref())
+    }
+}
+
+/// Shared by [`PyReader::coordinate_reference_system`] and
+/// [`crate::element::PyElement::coordinate_reference_system`]: converts an optional [`omf::Crs`]
+/// into a Python dict, or `None`.
+pub(crate) fn crs_to_py(py: Python<'_>, crs: Option<&omf::Crs>) -> PyResult<PyObject> {
+    match crs {
+        Some(crs) => {
+            let json = serde_json::to_string(crs).map_err(|e| PyIOError::new_err(e.to_string()))?;
+            let json_mod = py.import("json")?;
+            json_mod.call_method1("loads", (json,)).map(|obj| obj.into())
+        }
+        None => Ok(py.None()),
+    }
+}
@@ -0,0 +1,286 @@
+use std::fs::File;
+use std::io::Cursor;
+
+use numpy::{PyReadonlyArray1, PyReadonlyArray2};
+use pyo3::exceptions::PyIOError;
+use pyo3::prelude::*;
+
+use omf::attribute::{Attribute, AttributeData};
+use omf::file::Writer;
+use omf::geometry::{Geometry, LineSet, PointSet, Surface};
+use omf::project::{Element, Project};
+
+use crate::error::to_py_err;
+
+/// Where a [`PyWriter`] writes its bytes, and the live [`omf::file::Writer`] doing it. Arrays are
+/// written straight through to whichever one this holds as each `add_*`/`add_number_attribute`
+/// call brings them in, rather than buffered up for `finish()` to write all at once: a numpy
+/// array handed to an `add_*` method is reinterpreted as bytes with `bytemuck::cast_slice` and
+/// passed directly to [`omf::file::Writer::write_array`] without an intervening copy, so a caller
+/// streaming a 50-million-vertex surface pays for exactly one buffer, numpy's own. The one
+/// exception is `add_number_attribute` with a `mask`, which writes a `NaN`-substituted copy.
+enum WriteTarget {
+    /// A path on disk, written directly with [`omf::file::Writer::create`].
+    Path(Writer<File>),
+    /// Held in memory; `finish()` returns the encoded bytes instead of writing to disk, for
+    /// callers (e.g. web services) that want to stream the result themselves without a temporary
+    /// file.
+    Memory(Writer<Cursor<Vec<u8>>>),
+}
+
+/// Python wrapper around [`omf::file::Writer`], building up a [`Project`] from numpy arrays and
+/// writing it out with [`PyWriter::finish`].
+///
+/// Mirrors `omf::file::Writer`: elements are added one at a time via `add_*` methods, each
+/// returning the element's index for later attribute attachment, and their backing arrays are
+/// written out immediately (see [`WriteTarget`]); nothing else happens until `finish()` is
+/// called, which validates and writes `project.json` to close out the container.
+#[pyclass(name = "Writer")]
+pub struct PyWriter {
+    target: Option<WriteTarget>,
+    project: Project,
+    validation_options: omf::problem::ValidationOptions,
+}
+
+#[pymethods]
+impl PyWriter {
+    #[new]
+    fn new(path: &str, name: &str) -> PyResult<Self> {
+        let writer = Writer::create(path).map_err(to_py_err)?;
+        Ok(Self {
+            target: Some(WriteTarget::Path(writer)),
+            project: Project::new(name),
+            validation_options: omf::problem::ValidationOptions::default(),
+        })
+    }
+
+    /// Creates a writer that builds the `.omf` file entirely in memory, for callers (e.g. a
+    /// FastAPI or Django view) that want to hand the resulting bytes to `io.BytesIO` or stream
+    /// them directly in an HTTP response instead of writing a temporary file.
+    #[staticmethod]
+    fn in_memory(name: &str) -> PyResult<Self> {
+        let writer = Writer::new_in_memory().map_err(to_py_err)?;
+        Ok(Self {
+            target: Some(WriteTarget::Memory(writer)),
+            project: Project::new(name),
+            validation_options: omf::problem::ValidationOptions::default(),
+        })
+    }
+
+    /// Adds a point-set element from an `(n, 3)` float64 array of vertex positions.
+    fn add_point_set(&mut self, name: &str, vertices: PyReadonlyArray2<'_, f64>) -> PyResult<usize> {
+        let array_name = format!("{}-vertices", self.project.elements.len());
+        self.write_array(&array_name, bytemuck::cast_slice(vertices.as_slice()?))?;
+        self.project.elements.push(Element {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            geometry: Geometry::PointSet(PointSet { vertices: array_name, origin: [0.0; 3] }),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        Ok(self.project.elements.len() - 1)
+    }
+
+    /// Adds a line-set element from an `(n, 3)` vertex array and an `(m, 2)` segment index array.
+    fn add_line_set(
+        &mut self,
+        name: &str,
+        vertices: PyReadonlyArray2<'_, f64>,
+        segments: PyReadonlyArray2<'_, u32>,
+    ) -> PyResult<usize> {
+        let index = self.project.elements.len();
+        let vertices_name = format!("{index}-vertices");
+        let segments_name = format!("{index}-segments");
+        self.write_array(&vertices_name, bytemuck::cast_slice(vertices.as_slice()?))?;
+        self.write_array(&segments_name, bytemuck::cast_slice(segments.as_slice()?))?;
+        self.project.elements.push(Element {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            geometry: Geometry::LineSet(LineSet {
+                vertices: vertices_name,
+                segments: segments_name,
+                wide_indices: false,
+                origin: [0.0; 3],
+            }),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        Ok(index)
+    }
+
+    /// Adds a triangulated surface element from an `(n, 3)` vertex array and an `(m, 3)`
+    /// triangle index array.
+    fn add_surface(
+        &mut self,
+        name: &str,
+        vertices: PyReadonlyArray2<'_, f64>,
+        triangles: PyReadonlyArray2<'_, u32>,
+    ) -> PyResult<usize> {
+        let index = self.project.elements.len();
+        let vertices_name = format!("{index}-vertices");
+        let triangles_name = format!("{index}-triangles");
+        self.write_array(&vertices_name, bytemuck::cast_slice(vertices.as_slice()?))?;
+        self.write_array(&triangles_name, bytemuck::cast_slice(triangles.as_slice()?))?;
+        self.project.elements.push(Element {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            geometry: Geometry::Surface(Surface {
+                vertices: vertices_name,
+                triangles: triangles_name,
+                wide_indices: false,
+                closed: false,
+                origin: [0.0; 3],
+            }),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        Ok(index)
+    }
+
+    /// Attaches a numeric attribute to a previously added element. `mask`, if given, is a boolean
+    /// array the same length as `values`; `True` marks a value as null. The crate has no separate
+    /// null-bitmap storage for Number attributes (see [`omf::attribute::NullRunStats`]), so masked
+    /// entries are written as `NaN`, the convention every other reader of this format already
+    /// treats as null.
+    #[pyo3(signature = (element, name, location, values, mask=None))]
+    fn add_number_attribute(
+        &mut self,
+        element: usize,
+        name: &str,
+        location: &str,
+        values: PyReadonlyArray1<'_, f64>,
+        mask: Option<PyReadonlyArray1<'_, bool>>,
+    ) -> PyResult<()> {
+        if element >= self.project.elements.len() {
+            return Err(PyIOError::new_err("no such element"));
+        }
+        let array_name = format!("{element}-{name}-values");
+        match mask {
+            None => self.write_array(&array_name, bytemuck::cast_slice(values.as_slice()?))?,
+            Some(mask) => {
+                let values = values.as_slice()?;
+                let mask = mask.as_slice()?;
+                if mask.len() != values.len() {
+                    return Err(PyIOError::new_err(format!(
+                        "mask has {} values but values has {}; they must be the same length",
+                        mask.len(),
+                        values.len()
+                    )));
+                }
+                let masked: Vec<f64> =
+                    values.iter().zip(mask).map(|(&v, &null)| if null { f64::NAN } else { v }).collect();
+                self.write_array(&array_name, bytemuck::cast_slice(&masked))?;
+            }
+        };
+        self.project.elements[element].attributes.push(Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            location: location.to_string(),
+            data: AttributeData::Number { values: array_name },
+            metadata: Default::default(),
+        });
+        Ok(())
+    }
+
+    /// Sets a display hint (see [`omf::attribute::NumberDisplayHint`]) on a previously added
+    /// numeric attribute, so viewers can render its values (e.g. a gold grade or a tonnage)
+    /// consistently instead of each one picking its own formatting.
+    #[pyo3(signature = (element, name, decimal_places=None, scientific_notation=false, thousands_separator=false))]
+    fn set_number_display_hint(
+        &mut self,
+        element: usize,
+        name: &str,
+        decimal_places: Option<u32>,
+        scientific_notation: bool,
+        thousands_separator: bool,
+    ) -> PyResult<()> {
+        let attribute = self
+            .project
+            .elements
+            .get_mut(element)
+            .ok_or_else(|| PyIOError::new_err("no such element"))?
+            .attributes
+            .iter_mut()
+            .find(|a| a.name == name)
+            .ok_or_else(|| PyIOError::new_err(format!("no such attribute \"{name}\"")))?;
+        omf::attribute::set_display_hint(
+            attribute,
+            omf::attribute::NumberDisplayHint { decimal_places, scientific_notation, thousands_separator },
+        );
+        Ok(())
+    }
+
+    /// Appends an entry to the project's changelog (see `omf::changelog`), recording who changed
+    /// what and when so the delivery history travels inside the file itself. `timestamp` is
+    /// caller-supplied (e.g. `datetime.now(timezone.utc).isoformat()`), since this crate has no
+    /// time source of its own.
+    fn append_changelog_entry(
+        &mut self,
+        timestamp: &str,
+        author: &str,
+        description: &str,
+        affected_elements: Vec<String>,
+    ) {
+        omf::changelog::append(
+            &mut self.project,
+            omf::changelog::ChangelogEntry {
+                timestamp: timestamp.to_string(),
+                author: author.to_string(),
+                description: description.to_string(),
+                affected_elements,
+            },
+        );
+    }
+
+    /// Configures how `finish()` validates the project before writing it (see
+    /// [`omf::problem::ValidationOptions`]). `warnings_as_errors` promotes every non-fatal
+    /// problem to a hard failure; `skip_expensive_checks` skips checks that decode array
+    /// contents. Both are off by default.
+    fn set_validation_options(&mut self, warnings_as_errors: bool, skip_expensive_checks: bool) {
+        self.validation_options.warnings_as_errors = warnings_as_errors;
+        self.validation_options.skip_expensive_checks = skip_expensive_checks;
+    }
+
+    /// Validates the project and writes it, closing out the container. Every array registered by
+    /// the `add_*` methods above was already streamed out as it arrived (see [`WriteTarget`]);
+    /// this only writes `project.json` itself. Writers created with the constructor write to
+    /// their path and return `None`; writers created with [`PyWriter::in_memory`] return the
+    /// encoded file as `bytes`. Calling `finish()` a second time raises, since the underlying
+    /// [`omf::file::Writer`] is consumed the first time.
+    fn finish<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, pyo3::types::PyBytes>>> {
+        let target = self.target.take().ok_or_else(|| PyIOError::new_err("writer already finished"))?;
+        match target {
+            WriteTarget::Path(writer) => {
+                let writer = writer.with_validation_options(self.validation_options.clone());
+                let (_, _report, _benchmark, _problems) = writer.finish(&self.project).map_err(to_py_err)?;
+                Ok(None)
+            }
+            WriteTarget::Memory(writer) => {
+                let writer = writer.with_validation_options(self.validation_options.clone());
+                let (cursor, _report, _benchmark, _problems) = writer.finish(&self.project).map_err(to_py_err)?;
+                Ok(Some(pyo3::types::PyBytes::new_bound(py, &cursor.into_inner())))
+            }
+        }
+    }
+}
+
+impl PyWriter {
+    /// Writes `bytes` under `name` through whichever [`omf::file::Writer`] `self.target` holds,
+    /// immediately rather than buffering, so the caller's numpy array is never copied into an
+    /// intermediate holding buffer first.
+    fn write_array(&mut self, name: &str, bytes: &[u8]) -> PyResult<()> {
+        match self.target.as_mut().ok_or_else(|| PyIOError::new_err("writer already finished"))? {
+            WriteTarget::Path(writer) => writer.write_array(name, bytes).map_err(to_py_err),
+            WriteTarget::Memory(writer) => writer.write_array(name, bytes).map_err(to_py_err),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Standard convention for marking undefined ("air" or "void") blocks in a block model, and
+//! helpers that exclude them from volume and tonnage computations.
+//!
+//! There is no single agreed-upon way to flag blocks that fall outside the modeled volume;
+//! senders have historically used a magic category name, an all-null attribute, or an ad hoc
+//! Boolean field. This module fixes one convention so tools built on this crate can agree: a
+//! Boolean attribute named [`AIR_BLOCK_ATTRIBUTE_NAME`] where `true` means the block should be
+//! excluded from volumetrics.
+
+use crate::project::Element;
+use crate::AttributeData;
+
+/// The reserved attribute name marking a block as undefined ("air" or "void"). A value of `true`
+/// means the block should be excluded from volume, tonnage, and similar computations.
+pub const AIR_BLOCK_ATTRIBUTE_NAME: &str = "air_block";
+
+/// Returns the air-block attribute on `element`, if it has one.
+pub fn air_block_attribute(element: &Element) -> Option<&crate::Attribute> {
+    element.attributes.iter().find(|a| {
+        a.name == AIR_BLOCK_ATTRIBUTE_NAME && matches!(a.data, AttributeData::Boolean { .. })
+    })
+}
+
+/// Total volume of `element`'s blocks, in cubic units of its grid, excluding any marked as air
+/// via [`AIR_BLOCK_ATTRIBUTE_NAME`]. `air_flags` must have one entry per block, in the same
+/// order as the model's linearized block indices, and is typically read from the array named by
+/// [`air_block_attribute`].
+///
+/// Returns `None` if `element`'s geometry isn't a [`crate::geometry::BlockModel`].
+pub fn volume_excluding_air(element: &Element, air_flags: &[bool]) -> Option<f64> {
+    let crate::Geometry::BlockModel(model) = &element.geometry else {
+        return None;
+    };
+    let air_count = air_flags.iter().filter(|&&is_air| is_air).count() as u64;
+    let solid_blocks = model.block_count().saturating_sub(air_count);
+    Some(solid_blocks as f64 * model.block_volume())
+}
+
+/// Tonnage of `element`'s blocks at the given density (mass per cubic unit), excluding any
+/// marked as air. See [`volume_excluding_air`].
+pub fn tonnage_excluding_air(element: &Element, air_flags: &[bool], density: f64) -> Option<f64> {
+    volume_excluding_air(element, air_flags).map(|volume| volume * density)
+}
@@ -0,0 +1,28 @@
+//! Per-category display colors for a `Category` attribute, e.g. the lithology palette a geologist
+//! expects to see reproduced in every viewer that opens the file.
+//!
+//! OMF itself has no categorical color list (only [`super::GradientLegend`], which is for
+//! continuous Number ranges); this is a metadata convention, recorded under
+//! [`CATEGORY_COLORS_METADATA_KEY`] on the attribute's `metadata`, one color per entry in
+//! [`super::AttributeData::Category::names`], that consuming applications can choose to render.
+
+use serde::{Deserialize, Serialize};
+
+use super::Attribute;
+
+/// The attribute metadata key under which a category color list is stored.
+pub const CATEGORY_COLORS_METADATA_KEY: &str = "category_colors";
+
+/// Records `colors` on `attribute`'s metadata, one `[r, g, b, a]` color per category, in the same
+/// order as [`super::AttributeData::Category::names`].
+pub fn set_category_colors(attribute: &mut Attribute, colors: Vec<[u8; 4]>) {
+    attribute.metadata.insert(
+        CATEGORY_COLORS_METADATA_KEY.to_string(),
+        serde_json::to_value(colors).expect("a Vec<[u8; 4]> always serializes"),
+    );
+}
+
+/// Reads the category color list recorded on `attribute`'s metadata, if any and well-formed.
+pub fn category_colors_of(attribute: &Attribute) -> Option<Vec<[u8; 4]>> {
+    attribute.metadata.get(CATEGORY_COLORS_METADATA_KEY).and_then(|v| serde_json::from_value(v.clone()).ok())
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AttributeRef, GradientLegend};
+
+/// A colormap tying a [`GradientLegend`] to a specific attribute by its stable
+/// [`super::Attribute::id`], so renaming the attribute doesn't break the colormap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Colormap {
+    /// The attribute this colormap applies to.
+    pub attribute: AttributeRef,
+    /// The color gradient itself.
+    pub legend: GradientLegend,
+}
@@ -0,0 +1,126 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The values of an [`super::Attribute`] and how they should be interpreted.
+///
+/// Deserialization is lenient by default: an attribute whose `type` is not one recognized by
+/// this version of the crate becomes [`AttributeData::Unknown`] instead of failing the whole
+/// file, so older readers can still load everything else out of a file written by a newer
+/// writer. The unknown attribute round-trips untouched if the project is written back out.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum AttributeData {
+    /// Floating-point or signed integer numeric values, one per location.
+    Number {
+        /// Reference to the values array in the file's data section.
+        values: String,
+    },
+    /// Category values, storing an index into `names`/`colors` per location. Commonly used for
+    /// discrete geological codes (lithology, alteration, mineralization).
+    Category {
+        /// Reference to the index array in the file's data section.
+        values: String,
+        /// Names for each category index.
+        names: Vec<String>,
+        /// Optional longer description for each category index, in the same order as `names`.
+        /// Empty if not provided, e.g. by older files.
+        #[serde(default)]
+        descriptions: Vec<String>,
+    },
+    /// Boolean or filter values, one per location.
+    Boolean {
+        /// Reference to the values array in the file's data section.
+        values: String,
+    },
+    /// Free-form text values, one per location.
+    Text {
+        /// Reference to the values array in the file's data section.
+        values: String,
+    },
+    /// Texture (UV) coordinates, one pair per location.
+    Texcoord {
+        /// Reference to the coordinate array in the file's data section: `[f32; 2]` per location
+        /// if `quantized` is false, or `[u16; 2]` per location (see [`super::quantize`]) if true.
+        values: String,
+        /// If true, `values` stores each coordinate normalized to `[0, 65535]` over `[0.0, 1.0]`
+        /// instead of raw `f32`, cutting storage in half. Dequantize with [`super::dequantize`].
+        #[serde(default)]
+        quantized: bool,
+    },
+    /// An attribute data type not recognized by this version of the crate, preserved so it can
+    /// be round-tripped or skipped rather than failing the whole file to read.
+    #[serde(skip_serializing)]
+    Unknown {
+        /// The unrecognized `type` value as it appeared in the JSON.
+        type_name: String,
+        /// The full JSON object for this attribute data, preserved untouched.
+        raw_json: serde_json::Value,
+    },
+}
+
+impl Serialize for AttributeData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // `Unknown` round-trips its original JSON verbatim; every other variant uses the normal
+        // derived tagged-enum representation via an internal mirror type.
+        if let AttributeData::Unknown { raw_json, .. } = self {
+            return raw_json.serialize(serializer);
+        }
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum Tagged<'a> {
+            Number { values: &'a str },
+            Category { values: &'a str, names: &'a [String], descriptions: &'a [String] },
+            Boolean { values: &'a str },
+            Text { values: &'a str },
+            Texcoord { values: &'a str, quantized: bool },
+        }
+        let tagged = match self {
+            AttributeData::Number { values } => Tagged::Number { values },
+            AttributeData::Category { values, names, descriptions } => {
+                Tagged::Category { values, names, descriptions }
+            }
+            AttributeData::Boolean { values } => Tagged::Boolean { values },
+            AttributeData::Text { values } => Tagged::Text { values },
+            AttributeData::Texcoord { values, quantized } => Tagged::Texcoord { values, quantized: *quantized },
+            AttributeData::Unknown { .. } => unreachable!(),
+        };
+        tagged.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AttributeData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw_json = serde_json::Value::deserialize(deserializer)?;
+        let type_name = raw_json
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| D::Error::custom("attribute data is missing its \"type\" field"))?
+            .to_string();
+        #[derive(Deserialize)]
+        #[serde(tag = "type")]
+        enum Known {
+            Number { values: String },
+            Category { values: String, names: Vec<String>, #[serde(default)] descriptions: Vec<String> },
+            Boolean { values: String },
+            Text { values: String },
+            Texcoord { values: String, #[serde(default)] quantized: bool },
+        }
+        match serde_json::from_value::<Known>(raw_json.clone()) {
+            Ok(Known::Number { values }) => Ok(AttributeData::Number { values }),
+            Ok(Known::Category { values, names, descriptions }) => {
+                Ok(AttributeData::Category { values, names, descriptions })
+            }
+            Ok(Known::Boolean { values }) => Ok(AttributeData::Boolean { values }),
+            Ok(Known::Text { values }) => Ok(AttributeData::Text { values }),
+            Ok(Known::Texcoord { values, quantized }) => Ok(AttributeData::Texcoord { values, quantized }),
+            Err(_) => Ok(AttributeData::Unknown { type_name, raw_json }),
+        }
+    }
+}
+
+impl AttributeData {
+    /// True if this is an [`AttributeData::Unknown`] preserved from a newer file format.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, AttributeData::Unknown { .. })
+    }
+}
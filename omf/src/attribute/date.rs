@@ -0,0 +1,32 @@
+/// Converts a count of days since the Unix epoch (as commonly stored by legacy formats and the C
+/// API, which can't pass `chrono` types directly) into `(year, month, day)`, checking for
+/// overflow instead of silently wrapping the way a naive `as i32` cast would for values far in
+/// the future or past.
+pub fn days_since_epoch_to_ymd(days: i64) -> Option<(i32, u32, u32)> {
+    // civil_from_days: Howard Hinnant's date algorithm, valid across the full i64 range without
+    // intermediate overflow, unlike a naive Julian-day conversion.
+    let z = days.checked_add(719_468)?;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+    let year = i32::try_from(y).ok()?;
+    Some((year, m, d))
+}
+
+/// The inverse of [`days_since_epoch_to_ymd`], returning `None` if the date is out of the range
+/// representable as days-since-epoch without overflow.
+pub fn ymd_to_days_since_epoch(year: i32, month: u32, day: u32) -> Option<i64> {
+    let y = if month <= 2 { year as i64 - 1 } else { year as i64 };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 } as u64;
+    let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    (era * 146_097).checked_add(doe as i64)?.checked_sub(719_468)
+}
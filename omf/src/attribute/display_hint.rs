@@ -0,0 +1,43 @@
+//! Display formatting hints for Number attribute values (preferred decimal places, scientific
+//! notation, thousands separator), so a value like a gold grade or a tonnage renders consistently
+//! across every application that reads the file instead of each one picking its own formatting.
+//!
+//! Stored as a metadata convention under [`NUMBER_DISPLAY_HINT_METADATA_KEY`], following the same
+//! pattern as [`crate::classification`]. Purely advisory: it changes nothing about how values are
+//! stored or computed, and readers are free to ignore it.
+
+use serde::{Deserialize, Serialize};
+
+use super::Attribute;
+
+/// The attribute metadata key under which a [`NumberDisplayHint`] is stored.
+pub const NUMBER_DISPLAY_HINT_METADATA_KEY: &str = "number_display_hint";
+
+/// Suggested display formatting for a Number attribute's values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct NumberDisplayHint {
+    /// Preferred number of digits after the decimal point, e.g. `2` for a percentage grade.
+    /// `None` leaves it to the consuming application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimal_places: Option<u32>,
+    /// If true, values should be rendered in scientific notation (e.g. `1.2e6`) rather than
+    /// fixed-point.
+    #[serde(default)]
+    pub scientific_notation: bool,
+    /// If true, large values should be grouped with a thousands separator (e.g. `1,234,567`).
+    #[serde(default)]
+    pub thousands_separator: bool,
+}
+
+/// Records `hint` on `attribute`'s metadata.
+pub fn set_display_hint(attribute: &mut Attribute, hint: NumberDisplayHint) {
+    attribute.metadata.insert(
+        NUMBER_DISPLAY_HINT_METADATA_KEY.to_string(),
+        serde_json::to_value(hint).expect("NumberDisplayHint always serializes"),
+    );
+}
+
+/// Reads the display hint recorded on `attribute`'s metadata, if any and well-formed.
+pub fn display_hint_of(attribute: &Attribute) -> Option<NumberDisplayHint> {
+    attribute.metadata.get(NUMBER_DISPLAY_HINT_METADATA_KEY).and_then(|v| serde_json::from_value(v.clone()).ok())
+}
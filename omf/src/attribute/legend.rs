@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// A color gradient legend for a numeric attribute, mapping its value range onto a sequence of
+/// colors for display, e.g. by a 3D viewer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GradientLegend {
+    /// Value at the start of the gradient.
+    pub min: f64,
+    /// Value at the end of the gradient.
+    pub max: f64,
+    /// Colors evenly spaced across `[min, max]`, as `[r, g, b, a]` bytes.
+    pub colors: Vec<[u8; 4]>,
+}
+
+impl GradientLegend {
+    /// Builds a default legend spanning `[min, max]` using a simple blue-to-red gradient,
+    /// suitable when the caller has no domain-specific color scheme in mind.
+    pub fn default_for_range(min: f64, max: f64) -> Self {
+        const STEPS: usize = 32;
+        let colors = (0..STEPS)
+            .map(|i| {
+                let t = i as f64 / (STEPS - 1) as f64;
+                [(t * 255.0) as u8, 0, ((1.0 - t) * 255.0) as u8, 255]
+            })
+            .collect();
+        Self { min, max, colors }
+    }
+
+    /// Looks up the color for `value`, clamping to the legend's range.
+    pub fn color_for(&self, value: f64) -> [u8; 4] {
+        if self.colors.is_empty() {
+            return [0, 0, 0, 0];
+        }
+        let t = if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let index = ((t * (self.colors.len() - 1) as f64).round() as usize).min(self.colors.len() - 1);
+        self.colors[index]
+    }
+}
+
+/// Builds a [`GradientLegend`] automatically from a numeric attribute's values, using their
+/// observed min/max, so a legend doesn't have to be authored by hand for every attribute.
+pub fn auto_legend(values: &[f64]) -> Option<GradientLegend> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+    Some(GradientLegend::default_for_range(min, max))
+}
@@ -0,0 +1,72 @@
+//! Attribute values attached to the vertices, faces, cells or other locations of an element.
+
+mod air_blocks;
+mod category_colors;
+mod colormap;
+mod data;
+mod date;
+mod display_hint;
+mod legend;
+mod null_stats;
+mod texcoord;
+
+pub use air_blocks::{
+    air_block_attribute, tonnage_excluding_air, volume_excluding_air, AIR_BLOCK_ATTRIBUTE_NAME,
+};
+pub use category_colors::{category_colors_of, set_category_colors, CATEGORY_COLORS_METADATA_KEY};
+pub use colormap::Colormap;
+pub use data::AttributeData;
+pub use date::{days_since_epoch_to_ymd, ymd_to_days_since_epoch};
+pub use display_hint::{
+    display_hint_of, set_display_hint, NumberDisplayHint, NUMBER_DISPLAY_HINT_METADATA_KEY,
+};
+pub use legend::{auto_legend, GradientLegend};
+pub use null_stats::{AttributeSummary, NullRunStats};
+pub use texcoord::{dequantize, quantize, TexcoordBounds};
+
+use serde::{Deserialize, Serialize};
+
+/// A named, typed set of values attached to one location type of an [`crate::Element`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attribute {
+    /// A stable identifier for this attribute, unique within its owning element and unaffected
+    /// by renaming. Colormaps and filters should reference this instead of `name`, so renaming
+    /// an attribute doesn't silently break anything pointing at it. Generated if not already
+    /// present when an attribute is first written.
+    #[serde(default = "new_attribute_id")]
+    pub id: String,
+    /// The attribute's name, unique within its owning element.
+    pub name: String,
+    /// Where on the element's geometry the values apply, e.g. `"vertices"` or `"faces"`.
+    pub location: String,
+    /// The values themselves, and how they should be interpreted.
+    pub data: AttributeData,
+    /// Free-form metadata, e.g. the quantization precision applied before writing (see
+    /// [`crate::quantization`]) or unit/scale display hints.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// Resolves a category index array through `lookup` (e.g. an [`AttributeData::Category`]'s
+/// `names`, or a [`Colormap`]'s legend colors, or any other list indexed by category index) in
+/// one streaming pass, so callers don't have to materialize the index array and do the join
+/// themselves. An index with no corresponding `lookup` entry (out of range) resolves to `None`.
+pub fn resolve_category_indices<T: Clone>(indices: &[u32], lookup: &[T]) -> Vec<Option<T>> {
+    indices.iter().map(|&index| lookup.get(index as usize).cloned()).collect()
+}
+
+fn new_attribute_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// A reference to an attribute by its stable [`Attribute::id`] rather than its name, used by
+/// colormaps, filters, or anything else that needs to survive the attribute being renamed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttributeRef(pub String);
+
+impl AttributeRef {
+    /// Resolves this reference against a list of attributes, returning the matching one if any.
+    pub fn resolve<'a>(&self, attributes: &'a [Attribute]) -> Option<&'a Attribute> {
+        attributes.iter().find(|a| a.id == self.0)
+    }
+}
@@ -0,0 +1,90 @@
+//! Null-run statistics for spotting attributes assigned to the wrong location, e.g. a per-hole
+//! grade attribute accidentally attached at `"vertices"` where almost every value ends up null.
+
+/// Null-fraction and longest-null-run statistics for one numeric attribute's values, treating
+/// `NaN` as null (matching [`super::super::file::ArraySummary`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullRunStats {
+    /// Number of values, including nulls.
+    pub count: u64,
+    /// Number of null values.
+    pub null_count: u64,
+    /// Length of the longest consecutive run of null values.
+    pub longest_null_run: u64,
+}
+
+impl NullRunStats {
+    /// Computes null-run statistics over `values`, treating `NaN` as null.
+    pub fn compute(values: &[f64]) -> Self {
+        let mut null_count = 0u64;
+        let mut longest_null_run = 0u64;
+        let mut current_run = 0u64;
+        for &value in values {
+            if value.is_nan() {
+                null_count += 1;
+                current_run += 1;
+                longest_null_run = longest_null_run.max(current_run);
+            } else {
+                current_run = 0;
+            }
+        }
+        Self { count: values.len() as u64, null_count, longest_null_run }
+    }
+
+    /// Fraction of values that are null, from `0.0` to `1.0`. `0.0` for an empty attribute.
+    pub fn null_fraction(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.null_count as f64 / self.count as f64
+        }
+    }
+
+    /// True if at least 99% of values are null, often a sign that the attribute was attached at
+    /// the wrong location (e.g. per-hole data spread across per-vertex locations).
+    pub fn is_mostly_null(&self) -> bool {
+        self.null_fraction() >= 0.99
+    }
+}
+
+/// A coarser null-count and validity summary that applies across every [`super::AttributeData`]
+/// variant with a defined null convention, unlike [`NullRunStats`] (which is specific to
+/// [`super::AttributeData::Number`] and also tracks the longest null run). Built by
+/// [`crate::file::Reader::attribute_summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeSummary {
+    /// Number of values, including nulls.
+    pub count: u64,
+    /// Number of null values, under whichever null convention `data`'s variant uses: `NaN` for
+    /// [`super::AttributeData::Number`], an index with no corresponding category name for
+    /// [`super::AttributeData::Category`].
+    pub null_count: u64,
+    /// Number of distinct non-null values, for a variant where that's cheap to report from
+    /// already-decoded metadata rather than a full scan: the number of categories actually used
+    /// for [`super::AttributeData::Category`], or the number of `true`/`false` values present for
+    /// [`super::AttributeData::Boolean`]. `None` for a variant (`Number`, `Text`, `Texcoord`) where
+    /// reporting this would mean building a full hash set over the values.
+    pub distinct_count: Option<u64>,
+}
+
+impl AttributeSummary {
+    /// Fraction of values that are null, from `0.0` to `1.0`. `0.0` for an empty attribute.
+    pub fn null_fraction(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.null_count as f64 / self.count as f64
+        }
+    }
+
+    /// True if at least 99% of values are null. See [`NullRunStats::is_mostly_null`].
+    pub fn is_mostly_null(&self) -> bool {
+        self.null_fraction() >= 0.99
+    }
+}
+
+impl From<NullRunStats> for AttributeSummary {
+    fn from(stats: NullRunStats) -> Self {
+        Self { count: stats.count, null_count: stats.null_count, distinct_count: None }
+    }
+}
@@ -0,0 +1,69 @@
+//! Quantized storage for texture coordinate ([`super::AttributeData::Texcoord`]) attributes.
+//!
+//! Dense photogrammetry meshes can carry millions of UV pairs; storing each coordinate as a
+//! normalized `u16` instead of `f32` cuts that storage in half, at a precision of `1/65535`, which
+//! is ample for texture sampling. [`quantize`] and [`dequantize`] convert between the two forms.
+
+/// Normalizes each coordinate from `[0.0, 1.0]` to `[0, 65535]`, clamping out-of-range input
+/// first (UVs can legitimately fall outside `[0, 1]` with wrapping texture modes, but the
+/// quantized form can't represent that).
+pub fn quantize(values: &[[f32; 2]]) -> Vec<[u16; 2]> {
+    values
+        .iter()
+        .map(|&[u, v]| [quantize_one(u), quantize_one(v)])
+        .collect()
+}
+
+fn quantize_one(value: f32) -> u16 {
+    (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+}
+
+/// The inverse of [`quantize`].
+pub fn dequantize(values: &[[u16; 2]]) -> Vec<[f32; 2]> {
+    values
+        .iter()
+        .map(|&[u, v]| [dequantize_one(u), dequantize_one(v)])
+        .collect()
+}
+
+fn dequantize_one(value: u16) -> f32 {
+    value as f32 / u16::MAX as f32
+}
+
+/// The minimum and maximum `u` and `v` coordinate seen in a texcoord attribute, as reported by
+/// [`crate::file::Reader::texcoord_bounds`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TexcoordBounds {
+    /// The smallest `u` and `v` coordinate seen.
+    pub min: [f32; 2],
+    /// The largest `u` and `v` coordinate seen.
+    pub max: [f32; 2],
+}
+
+impl TexcoordBounds {
+    /// Computes the bounds of `values`, or `None` if it's empty.
+    pub fn compute(values: &[[f32; 2]]) -> Option<Self> {
+        let mut iter = values.iter();
+        let first = *iter.next()?;
+        let mut bounds = TexcoordBounds { min: first, max: first };
+        for &[u, v] in iter {
+            bounds.min[0] = bounds.min[0].min(u);
+            bounds.min[1] = bounds.min[1].min(v);
+            bounds.max[0] = bounds.max[0].max(u);
+            bounds.max[1] = bounds.max[1].max(v);
+        }
+        Some(bounds)
+    }
+
+    /// True if these bounds fall so far outside the conventional `[0, 1]` UV range that they
+    /// suggest the texture coordinates were mapped incorrectly (e.g. left in pixel space, or
+    /// generated with the wrong scale), rather than legitimately relying on a wrapping texture
+    /// mode.
+    pub fn is_suspicious(&self) -> bool {
+        const MARGIN: f32 = 1.0;
+        self.min[0] < -MARGIN
+            || self.min[1] < -MARGIN
+            || self.max[0] > 1.0 + MARGIN
+            || self.max[1] > 1.0 + MARGIN
+    }
+}
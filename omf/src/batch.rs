@@ -0,0 +1,30 @@
+//! Processing many OMF files as a batch, isolating failures to the file that caused them.
+
+use std::path::{Path, PathBuf};
+
+use crate::file::Reader;
+use crate::{Error, Project};
+
+/// The outcome of processing one file in a [`process_batch`] run.
+pub struct BatchResult {
+    /// The file that was processed.
+    pub path: PathBuf,
+    /// `Ok` with the loaded project, or `Err` with the error that stopped processing of just
+    /// this file. A failure here never aborts the rest of the batch.
+    pub outcome: Result<Project, Error>,
+}
+
+/// Opens and reads the project JSON of every path in `paths`, continuing past individual
+/// failures (corrupt zip, invalid JSON, I/O error opening the file) instead of aborting the
+/// whole run. Useful for pipelines ingesting many files from external senders where any single
+/// one might be malformed.
+pub fn process_batch(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> Vec<BatchResult> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let path = path.as_ref().to_path_buf();
+            let outcome = Reader::open(&path).and_then(|mut reader| reader.project());
+            BatchResult { path, outcome }
+        })
+        .collect()
+}
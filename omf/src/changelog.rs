@@ -0,0 +1,46 @@
+//! A project's delivery history, e.g. "re-exported after client feedback on the drillhole
+//! assays," so it travels inside the file instead of living in a separate email thread.
+//!
+//! Stored as a metadata convention under [`CHANGELOG_METADATA_KEY`], following the same pattern
+//! as [`crate::classification`] and [`crate::omf1::provenance`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::Project;
+
+/// The project metadata key under which the changelog is stored, as a JSON array of
+/// [`ChangelogEntry`].
+pub const CHANGELOG_METADATA_KEY: &str = "changelog";
+
+/// One entry in a project's changelog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// When the change was made. The crate has no time source of its own, so callers supply
+    /// this, typically an RFC 3339 timestamp.
+    pub timestamp: String,
+    /// Who made the change, e.g. a name or email address.
+    pub author: String,
+    /// A human-readable description of what changed.
+    pub description: String,
+    /// The [`crate::Element::id`] of each element affected by this change, so the reference
+    /// survives the element being renamed.
+    #[serde(default)]
+    pub affected_elements: Vec<String>,
+}
+
+/// Appends `entry` to `project`'s changelog in its metadata, creating the list if this is the
+/// first entry.
+pub fn append(project: &mut Project, entry: ChangelogEntry) {
+    let mut entries = entries(project);
+    entries.push(entry);
+    project.metadata.insert(CHANGELOG_METADATA_KEY.to_string(), serde_json::to_value(entries).unwrap());
+}
+
+/// Reads `project`'s changelog, or an empty list if it doesn't have one.
+pub fn entries(project: &Project) -> Vec<ChangelogEntry> {
+    project
+        .metadata
+        .get(CHANGELOG_METADATA_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
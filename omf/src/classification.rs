@@ -0,0 +1,43 @@
+//! Element-level classification tags for coarse access control, e.g. restricting confidential
+//! resource models from being included in an external export.
+//!
+//! OMF itself has no notion of access control; this is a metadata convention, recorded under
+//! [`CLASSIFICATION_METADATA_KEY`] on an element's `metadata`, that consuming applications can
+//! choose to enforce.
+
+use serde::{Deserialize, Serialize};
+
+use crate::project::Element;
+
+/// The element metadata key under which a [`Classification`] is stored.
+pub const CLASSIFICATION_METADATA_KEY: &str = "classification";
+
+/// A coarse sensitivity level for an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Classification {
+    Public,
+    Internal,
+    Confidential,
+    Restricted,
+}
+
+/// Reads the classification recorded on `element`'s metadata, defaulting to
+/// [`Classification::Internal`] if it isn't tagged.
+pub fn classification_of(element: &Element) -> Classification {
+    element
+        .metadata
+        .get(CLASSIFICATION_METADATA_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or(Classification::Internal)
+}
+
+/// Filters `elements` down to those at or below `max_allowed`, e.g. for building an external
+/// export that excludes [`Classification::Confidential`] and [`Classification::Restricted`]
+/// elements.
+pub fn filter_by_classification(
+    elements: Vec<Element>,
+    max_allowed: Classification,
+) -> Vec<Element> {
+    elements.into_iter().filter(|e| classification_of(e) <= max_allowed).collect()
+}
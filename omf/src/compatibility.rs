@@ -0,0 +1,146 @@
+//! Downgrading a project for maximum compatibility with older or simpler OMF readers.
+
+/// How aggressively [`crate::file::Writer::finish`] should simplify a project before writing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompatibilityProfile {
+    /// Write the project as-is, using every feature this crate supports.
+    #[default]
+    Full,
+    /// Drop anything a maximally compatible reader might not understand, e.g. attributes of a
+    /// data type it predates. Recorded in the [`DowngradeReport`] returned from
+    /// [`crate::file::Writer::finish`], so callers know exactly what such a reader will not see.
+    MaximumCompatibility,
+}
+
+/// One change made to reach the requested [`CompatibilityProfile`].
+#[derive(Debug, Clone)]
+pub struct Downgrade {
+    /// The element the change was made to.
+    pub element: String,
+    /// What was altered or dropped, e.g. `"dropped 1 attribute(s) of an unrecognized data type"`.
+    pub description: String,
+}
+
+/// A structured record of every [`Downgrade`] [`crate::file::Writer::finish`] applied.
+/// Empty when writing with [`CompatibilityProfile::Full`], since nothing is altered.
+#[derive(Debug, Clone, Default)]
+pub struct DowngradeReport {
+    pub downgrades: Vec<Downgrade>,
+}
+
+impl DowngradeReport {
+    pub(crate) fn record(&mut self, element: &str, description: impl Into<String>) {
+        self.downgrades.push(Downgrade { element: element.to_string(), description: description.into() });
+    }
+}
+
+/// Applies `profile` to `project` in place, returning a report of what changed.
+pub(crate) fn downgrade(project: &mut crate::Project, profile: CompatibilityProfile) -> DowngradeReport {
+    let mut report = DowngradeReport::default();
+    if profile == CompatibilityProfile::Full {
+        return report;
+    }
+    for element in &mut project.elements {
+        downgrade_element(element, &mut report);
+    }
+    report
+}
+
+/// Downgrades one element in place, recursing into a [`crate::Geometry::Composite`]'s children so
+/// nothing nested inside one goes unreported, the same as [`crate::repair::repair`] and
+/// [`crate::file::Writer::finish`]'s validation walk it.
+fn downgrade_element(element: &mut crate::Element, report: &mut DowngradeReport) {
+    let before = element.attributes.len();
+    element.attributes.retain(|attribute| !attribute.data.is_unknown());
+    let dropped = before - element.attributes.len();
+    if dropped > 0 {
+        report.record(&element.name, format!("dropped {dropped} attribute(s) of an unrecognized data type"));
+    }
+    match &mut element.geometry {
+        crate::Geometry::Surface(surface) if surface.wide_indices => {
+            report.record(
+                &element.name,
+                "surface uses u64 (wide_indices) triangle indices, which older readers don't \
+                 understand; split it into meshes of at most u32::MAX vertices each before \
+                 writing at this compatibility profile, since downgrading can only edit \
+                 project metadata, not rewrite the mesh's array data",
+            );
+        }
+        crate::Geometry::LineSet(line_set) if line_set.wide_indices => {
+            report.record(
+                &element.name,
+                "line set uses u64 (wide_indices) segment indices, which older readers don't \
+                 understand; split it into line sets of at most u32::MAX vertices each before \
+                 writing at this compatibility profile, since downgrading can only edit \
+                 project metadata, not rewrite the array data",
+            );
+        }
+        crate::Geometry::Composite(composite) => {
+            for child in &mut composite.elements {
+                downgrade_element(child, report);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::attribute::{Attribute, AttributeData};
+    use crate::geometry::{Composite, PointSet};
+    use crate::{Element, Geometry, Project};
+
+    use super::*;
+
+    fn unknown_attribute() -> Attribute {
+        Attribute {
+            id: String::new(),
+            name: "future_attribute".to_string(),
+            location: "vertices".to_string(),
+            data: AttributeData::Unknown {
+                type_name: "SomeFutureType".to_string(),
+                raw_json: serde_json::json!({"type": "SomeFutureType"}),
+            },
+            metadata: Default::default(),
+        }
+    }
+
+    fn point_set_element(name: &str, attributes: Vec<Attribute>) -> Element {
+        Element {
+            id: String::new(),
+            name: name.to_string(),
+            description: String::new(),
+            geometry: Geometry::PointSet(PointSet { vertices: "vertices".to_string(), origin: [0.0; 3] }),
+            attributes,
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn downgrade_recurses_into_composite_children() {
+        let child = point_set_element("nested", vec![unknown_attribute()]);
+        let composite = Element {
+            id: String::new(),
+            name: "composite".to_string(),
+            description: String::new(),
+            geometry: Geometry::Composite(Composite { elements: vec![child] }),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        };
+        let mut project = Project::new("compatibility test");
+        project.elements.push(composite);
+
+        let report = downgrade(&mut project, CompatibilityProfile::MaximumCompatibility);
+
+        assert_eq!(report.downgrades.len(), 1);
+        assert_eq!(report.downgrades[0].element, "nested");
+        let Geometry::Composite(composite) = &project.elements[0].geometry else {
+            panic!("expected a composite geometry");
+        };
+        assert!(composite.elements[0].attributes.is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+//! Registration point for format extensions built as composite-element conventions.
+//!
+//! OMF has no first-class extension mechanism, but composite elements plus a reserved metadata
+//! key are enough to build one: a convention is a well-known string recorded in a composite's
+//! `metadata["convention"]` field, plus a validator that knows what child elements and
+//! attributes it expects. This module lets consumers register their own conventions and look
+//! them up generically instead of hard-coding `if name == "..."` checks everywhere a composite
+//! is handled.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::geometry::Composite;
+
+/// The metadata key on a composite element's element-level metadata that names its convention.
+pub const CONVENTION_METADATA_KEY: &str = "convention";
+
+/// A format extension implemented as a composite-element convention.
+pub trait Convention: Send + Sync {
+    /// The convention's well-known name, matched against `metadata["convention"]`.
+    fn name(&self) -> &str;
+
+    /// Checks that `composite` conforms to this convention, returning a description of the
+    /// first problem found, if any.
+    fn validate(&self, composite: &Composite) -> Option<String>;
+}
+
+static REGISTRY: Lazy<RwLock<HashMap<String, Box<dyn Convention>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Registers a convention, replacing any previously registered under the same name.
+pub fn register_convention(convention: Box<dyn Convention>) {
+    REGISTRY.write().unwrap().insert(convention.name().to_string(), convention);
+}
+
+/// Validates `composite` against its named convention, if one is registered and it declares one
+/// via [`CONVENTION_METADATA_KEY`]. Returns `Ok(())` if there's no declared convention, no
+/// registered handler for it, or validation passed.
+pub fn validate_against_convention(
+    metadata: &std::collections::BTreeMap<String, serde_json::Value>,
+    composite: &Composite,
+) -> Result<(), String> {
+    let Some(name) = metadata.get(CONVENTION_METADATA_KEY).and_then(|v| v.as_str()) else {
+        return Ok(());
+    };
+    let registry = REGISTRY.read().unwrap();
+    match registry.get(name) {
+        Some(convention) => match convention.validate(composite) {
+            Some(problem) => Err(problem),
+            None => Ok(()),
+        },
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,73 @@
+//! Coordinate reference systems: what a project's or element's coordinates mean in the real
+//! world. See the [OMF specification](https://github.com/gmggroup/omf-rust) for the on-disk JSON
+//! shape, which follows the same `{"type": ...}`-tagged convention as [`crate::Geometry`] and
+//! [`crate::AttributeData`].
+
+use serde::{Deserialize, Serialize};
+
+/// A coordinate reference system, attached to a whole project
+/// ([`crate::Project::coordinate_reference_system`]) or, to override it, to one element
+/// ([`crate::Element::coordinate_reference_system`]) surveyed in a different system than the rest
+/// (e.g. drillhole collars still recorded in a local mine grid).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Crs {
+    /// An EPSG registry code, e.g. `4326` for WGS 84 or `32633` for UTM zone 33N.
+    Epsg {
+        /// The numeric EPSG code.
+        code: u32,
+    },
+    /// A full coordinate system definition as WKT2 text (ISO 19162), for systems with no EPSG
+    /// code or that need parameters a code alone doesn't capture.
+    Wkt2 {
+        /// The WKT2 definition text.
+        wkt: String,
+    },
+    /// A local, non-georeferenced grid. See [`LocalGrid`].
+    LocalGrid(LocalGrid),
+}
+
+impl Crs {
+    /// Checks this CRS is internally consistent, returning a description of the first problem
+    /// found, if any. This crate has no EPSG registry or WKT2 grammar to validate against, so
+    /// only the structural invariants below are checked; a syntactically valid but nonexistent
+    /// EPSG code or malformed WKT2 string will pass.
+    pub fn validate(&self) -> Option<String> {
+        match self {
+            Crs::Epsg { code } if *code == 0 => Some("EPSG code 0 is not valid".to_string()),
+            Crs::Epsg { .. } => None,
+            Crs::Wkt2 { wkt } if wkt.trim().is_empty() => Some("WKT2 definition is empty".to_string()),
+            Crs::Wkt2 { .. } => None,
+            Crs::LocalGrid(grid) => grid.validate(),
+        }
+    }
+}
+
+/// A local, non-georeferenced coordinate grid, e.g. a mine grid defined by a false origin and a
+/// rotation from true north rather than a registered projection. See [`Crs::LocalGrid`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalGrid {
+    /// Human-readable name for the grid, e.g. `"Mine Grid 2020"`.
+    pub name: String,
+    /// Azimuth in degrees clockwise from true north that the grid's +Y axis points, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub azimuth_degrees: Option<f64>,
+    /// The grid origin's real-world position, if tied to a georeferenced point, as
+    /// `[easting, northing, elevation]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<[f64; 3]>,
+}
+
+impl LocalGrid {
+    fn validate(&self) -> Option<String> {
+        if self.name.trim().is_empty() {
+            return Some("local grid CRS has no name".to_string());
+        }
+        if let Some(azimuth) = self.azimuth_degrees {
+            if !(0.0..360.0).contains(&azimuth) {
+                return Some(format!("local grid azimuth {azimuth} is outside [0, 360) degrees"));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,92 @@
+//! Comparing raw array data between files, without needing to know the schema that produced it.
+
+use std::io::{Read, Seek};
+
+use crate::file::Reader;
+use crate::Result;
+
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Compares the named array `array_a` in `reader_a` against `array_b` in `reader_b`, streaming
+/// both in fixed-size chunks so neither is fully materialized in memory, and exiting as soon as
+/// a difference is found. Useful for verifying that a migration or vendor hand-off reproduced
+/// data exactly.
+///
+/// If `tolerance` is `None`, the arrays must match byte for byte. If it's `Some`, both arrays are
+/// instead compared as `[f64]` (differences no larger than `tolerance` count as equal), and an
+/// array whose length isn't a multiple of 8 bytes is treated as unequal to anything.
+pub fn arrays_equal<R1, R2>(
+    reader_a: &mut Reader<R1>,
+    array_a: &str,
+    reader_b: &mut Reader<R2>,
+    array_b: &str,
+    tolerance: Option<f64>,
+) -> Result<bool>
+where
+    R1: Read + Seek,
+    R2: Read + Seek,
+{
+    let mut entry_a = reader_a.open_array(array_a)?;
+    let mut entry_b = reader_b.open_array(array_b)?;
+    if entry_a.size() != entry_b.size() {
+        return Ok(false);
+    }
+    match tolerance {
+        None => bytes_equal(&mut entry_a, &mut entry_b),
+        Some(tolerance) => floats_equal(&mut entry_a, &mut entry_b, tolerance),
+    }
+}
+
+fn bytes_equal(a: &mut impl Read, b: &mut impl Read) -> Result<bool> {
+    let mut buffer_a = [0u8; CHUNK_SIZE];
+    let mut buffer_b = [0u8; CHUNK_SIZE];
+    loop {
+        let read_a = read_full_chunk(a, &mut buffer_a)?;
+        let read_b = read_full_chunk(b, &mut buffer_b)?;
+        if read_a != read_b {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+        if buffer_a[..read_a] != buffer_b[..read_b] {
+            return Ok(false);
+        }
+    }
+}
+
+fn floats_equal(a: &mut impl Read, b: &mut impl Read, tolerance: f64) -> Result<bool> {
+    // Rounded down to a multiple of 8 so a chunk boundary never splits an `f64` in two.
+    const FLOAT_CHUNK_SIZE: usize = (CHUNK_SIZE / 8) * 8;
+    let mut buffer_a = [0u8; FLOAT_CHUNK_SIZE];
+    let mut buffer_b = [0u8; FLOAT_CHUNK_SIZE];
+    loop {
+        let read_a = read_full_chunk(a, &mut buffer_a)?;
+        let read_b = read_full_chunk(b, &mut buffer_b)?;
+        if read_a != read_b || read_a % 8 != 0 {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+        let values_a: &[f64] = bytemuck::cast_slice(&buffer_a[..read_a]);
+        let values_b: &[f64] = bytemuck::cast_slice(&buffer_b[..read_a]);
+        if values_a.iter().zip(values_b).any(|(x, y)| (x - y).abs() > tolerance) {
+            return Ok(false);
+        }
+    }
+}
+
+/// Reads until `buffer` is full or the source is exhausted, since a single `read` call isn't
+/// guaranteed to fill it.
+fn read_full_chunk(source: &mut impl Read, buffer: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        let read = source.read(&mut buffer[filled..])?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+    Ok(filled)
+}
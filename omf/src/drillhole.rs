@@ -0,0 +1,144 @@
+//! Convention for drillhole/desurveyed data: a [`crate::geometry::Composite`] made of a collar,
+//! a survey trace, and one or more interval line sets (assay, lithology, geotechnical logging,
+//! ...), tagged via [`DRILLHOLE_ROLE_METADATA_KEY`] so applications get an automatic mapping onto
+//! this well-known shape instead of guessing it from element names.
+//!
+//! Registered with [`crate::convention`] under [`DRILLHOLE_CONVENTION_NAME`] once
+//! [`register`] has been called; like every convention there, this is enforced by agreement
+//! between writers and readers, not by the OMF format itself.
+
+use serde::{Deserialize, Serialize};
+
+use crate::convention::{Convention, CONVENTION_METADATA_KEY};
+use crate::geometry::{Composite, Geometry};
+use crate::project::Element;
+
+/// The convention's name, matched against a composite's `metadata[CONVENTION_METADATA_KEY]`.
+pub const DRILLHOLE_CONVENTION_NAME: &str = "drillhole";
+
+/// The element metadata key on each child of a drillhole composite recording its
+/// [`DrillholeRole`].
+pub const DRILLHOLE_ROLE_METADATA_KEY: &str = "drillhole_role";
+
+/// The role one child element plays within a drillhole composite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DrillholeRole {
+    /// The collar: the hole's starting point at the surface. Modeled as a two-vertex, one-segment
+    /// [`crate::geometry::LineSet`] (surface point to the first survey station) rather than a
+    /// separate point-only geometry, so every child of the composite shares the same
+    /// [`crate::geometry::LineSet`] handling in readers and viewers.
+    Collar,
+    /// The desurveyed 3D trace, one vertex per survey station (or per interpolated point along
+    /// it), as a [`crate::geometry::LineSet`].
+    Survey,
+    /// Downhole intervals along the trace (assay, lithology, geotechnical logging, ...), as a
+    /// [`crate::geometry::LineSet`] sharing the survey's vertices. A drillhole composite may have
+    /// more than one of these, one per logged property.
+    Interval,
+}
+
+/// Builds a drillhole composite [`Element`] from its already-built child elements, tagging each
+/// with the [`DrillholeRole`] metadata this convention requires and setting the composite's own
+/// `metadata[CONVENTION_METADATA_KEY]` so [`DrillholeConvention::validate`] (and any other
+/// convention-aware reader) recognizes it.
+///
+/// Each child must already have [`crate::geometry::Geometry::LineSet`] geometry and whatever
+/// attributes the caller wants (assay values, lithology codes, survey azimuth/dip, ...); this
+/// function only tags roles and assembles the composite, it doesn't build geometry itself.
+pub fn new_drillhole(
+    name: impl Into<String>,
+    mut collar: Element,
+    mut survey: Element,
+    intervals: Vec<Element>,
+) -> Element {
+    collar.metadata.insert(DRILLHOLE_ROLE_METADATA_KEY.to_string(), serde_json::json!(DrillholeRole::Collar));
+    survey.metadata.insert(DRILLHOLE_ROLE_METADATA_KEY.to_string(), serde_json::json!(DrillholeRole::Survey));
+    let mut elements = vec![collar, survey];
+    for mut interval in intervals {
+        interval.metadata.insert(DRILLHOLE_ROLE_METADATA_KEY.to_string(), serde_json::json!(DrillholeRole::Interval));
+        elements.push(interval);
+    }
+    let mut metadata = std::collections::BTreeMap::new();
+    metadata.insert(CONVENTION_METADATA_KEY.to_string(), serde_json::json!(DRILLHOLE_CONVENTION_NAME));
+    Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.into(),
+        description: String::new(),
+        geometry: Geometry::Composite(Composite { elements }),
+        attributes: Vec::new(),
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata,
+    }
+}
+
+/// Reads the [`DrillholeRole`] recorded on `element`'s metadata, if any and well-formed.
+pub fn role_of(element: &Element) -> Option<DrillholeRole> {
+    element.metadata.get(DRILLHOLE_ROLE_METADATA_KEY).and_then(|value| serde_json::from_value(value.clone()).ok())
+}
+
+/// The registered [`Convention`] for drillhole composites. Register it with
+/// [`crate::convention::register_convention`] (see [`register`]) so
+/// [`crate::convention::validate_against_convention`] picks it up.
+pub struct DrillholeConvention;
+
+impl Convention for DrillholeConvention {
+    fn name(&self) -> &str {
+        DRILLHOLE_CONVENTION_NAME
+    }
+
+    fn validate(&self, composite: &Composite) -> Option<String> {
+        let mut collar_count = 0;
+        let mut survey_count = 0;
+        let mut interval_count = 0;
+        for element in &composite.elements {
+            if !matches!(element.geometry, Geometry::LineSet(_)) {
+                return Some(format!(
+                    "drillhole child \"{}\" must have LineSet geometry, not {}",
+                    element.name,
+                    geometry_type_name(&element.geometry)
+                ));
+            }
+            match role_of(element) {
+                Some(DrillholeRole::Collar) => collar_count += 1,
+                Some(DrillholeRole::Survey) => survey_count += 1,
+                Some(DrillholeRole::Interval) => interval_count += 1,
+                None => {
+                    return Some(format!(
+                        "drillhole child \"{}\" is missing the \"{DRILLHOLE_ROLE_METADATA_KEY}\" metadata key",
+                        element.name
+                    ))
+                }
+            }
+        }
+        if collar_count != 1 {
+            return Some(format!("drillhole composite must have exactly one collar child, found {collar_count}"));
+        }
+        if survey_count != 1 {
+            return Some(format!("drillhole composite must have exactly one survey child, found {survey_count}"));
+        }
+        if interval_count == 0 {
+            return Some("drillhole composite must have at least one interval child".to_string());
+        }
+        None
+    }
+}
+
+fn geometry_type_name(geometry: &Geometry) -> &'static str {
+    match geometry {
+        Geometry::PointSet(_) => "PointSet",
+        Geometry::LineSet(_) => "LineSet",
+        Geometry::Surface(_) => "Surface",
+        Geometry::Composite(_) => "Composite",
+        Geometry::BlockModel(_) => "BlockModel",
+        Geometry::GridSurface(_) => "GridSurface",
+    }
+}
+
+/// Registers [`DrillholeConvention`] with [`crate::convention::register_convention`]. Not called
+/// automatically: like every convention, opting in is a deliberate choice for embedders that want
+/// [`crate::convention::validate_against_convention`] to enforce this shape.
+pub fn register() {
+    crate::convention::register_convention(Box::new(DrillholeConvention));
+}
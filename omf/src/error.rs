@@ -0,0 +1,93 @@
+//! Error and result types shared across the crate.
+
+use std::io;
+
+/// Convenience alias for `Result<T, Error>`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can occur while reading or writing OMF files.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An underlying I/O error.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// The zip container could not be read or written.
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    /// The project JSON could not be parsed or serialized.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The file is not a valid OMF file.
+    #[error("not a valid OMF file: {0}")]
+    InvalidFile(String),
+    /// The project failed validation and cannot be written or was rejected on read.
+    #[error("validation failed: {0}")]
+    Validation(String),
+    /// A limit configured in [`crate::Limits`] was exceeded.
+    #[error("limit exceeded: {0}")]
+    LimitExceeded(String),
+    /// The operation was stopped early via a [`crate::cancel::CancellationToken`].
+    #[error("operation cancelled")]
+    Cancelled,
+}
+
+/// A stable numeric identifier for each [`Error`] variant, for bindings (the C API, Python
+/// exceptions) that want to switch on the kind of failure without string-matching
+/// [`Error`]'s `Display` message, which is free to change wording between releases.
+///
+/// `omf-python` raises [`Error::code`] with its exceptions, and the C API looks code names up
+/// from [`ErrorCode::ALL`], rather than either maintaining its own copy of this table, so the
+/// three can't drift out of sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ErrorCode {
+    Io = 1,
+    Zip = 2,
+    Json = 3,
+    InvalidFile = 4,
+    Validation = 5,
+    LimitExceeded = 6,
+    Cancelled = 7,
+}
+
+impl ErrorCode {
+    /// Every code, in ascending order, for bindings that want to build a complete conversion
+    /// table (e.g. a C enum or a Python exception-class map) instead of hand-copying one.
+    pub const ALL: [ErrorCode; 7] = [
+        ErrorCode::Io,
+        ErrorCode::Zip,
+        ErrorCode::Json,
+        ErrorCode::InvalidFile,
+        ErrorCode::Validation,
+        ErrorCode::LimitExceeded,
+        ErrorCode::Cancelled,
+    ];
+
+    /// A short, stable, machine-readable name for the code, e.g. `"limit_exceeded"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorCode::Io => "io",
+            ErrorCode::Zip => "zip",
+            ErrorCode::Json => "json",
+            ErrorCode::InvalidFile => "invalid_file",
+            ErrorCode::Validation => "validation",
+            ErrorCode::LimitExceeded => "limit_exceeded",
+            ErrorCode::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl Error {
+    /// The stable [`ErrorCode`] for this error, for bindings to switch on.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Io(_) => ErrorCode::Io,
+            Error::Zip(_) => ErrorCode::Zip,
+            Error::Json(_) => ErrorCode::Json,
+            Error::InvalidFile(_) => ErrorCode::InvalidFile,
+            Error::Validation(_) => ErrorCode::Validation,
+            Error::LimitExceeded(_) => ErrorCode::LimitExceeded,
+            Error::Cancelled => ErrorCode::Cancelled,
+        }
+    }
+}
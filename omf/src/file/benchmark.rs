@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Time spent producing one array written by a [`super::Writer`], as recorded when
+/// [`super::Writer::with_benchmarking`] is enabled.
+#[derive(Debug, Clone)]
+pub struct ArrayTiming {
+    /// The array's name.
+    pub name: String,
+    /// Time spent encoding or otherwise computing the array's bytes before writing them, e.g. in
+    /// [`super::Writer::write_arrays_parallel`]'s `encode` callback. Zero for arrays written via
+    /// [`super::Writer::write_array`] directly, since the caller already had the bytes in hand.
+    pub encode: Duration,
+    /// Time spent compressing and writing the array's bytes into the zip container.
+    pub write: Duration,
+}
+
+/// A per-array timing breakdown produced by [`super::Writer::finish`] when
+/// [`super::Writer::with_benchmarking`] is enabled. Empty otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkReport {
+    pub arrays: Vec<ArrayTiming>,
+}
+
+impl BenchmarkReport {
+    /// Total time spent encoding, across every recorded array.
+    pub fn total_encode(&self) -> Duration {
+        self.arrays.iter().map(|a| a.encode).sum()
+    }
+
+    /// Total time spent compressing and writing, across every recorded array.
+    pub fn total_write(&self) -> Duration {
+        self.arrays.iter().map(|a| a.write).sum()
+    }
+}
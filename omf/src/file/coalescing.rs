@@ -0,0 +1,60 @@
+use std::io::{Read, Result as IoResult, Seek, SeekFrom};
+
+/// Wraps a `Read + Seek` source, buffering reads so that several small, adjacent reads (typical
+/// when pulling many small arrays like grid tensors or category legends out of a zip archive)
+/// turn into fewer, larger underlying reads instead of one syscall each.
+pub struct CoalescingReader<T> {
+    inner: T,
+    buffer: Vec<u8>,
+    buffer_start: u64,
+    position: u64,
+    chunk_size: usize,
+}
+
+impl<T: Read + Seek> CoalescingReader<T> {
+    /// Wraps `inner`, reading ahead in chunks of `chunk_size` bytes.
+    pub fn new(inner: T, chunk_size: usize) -> Self {
+        Self { inner, buffer: Vec::new(), buffer_start: 0, position: 0, chunk_size }
+    }
+
+    fn buffer_end(&self) -> u64 {
+        self.buffer_start + self.buffer.len() as u64
+    }
+
+    fn refill(&mut self) -> IoResult<()> {
+        self.inner.seek(SeekFrom::Start(self.position))?;
+        self.buffer.resize(self.chunk_size, 0);
+        let read = self.inner.read(&mut self.buffer)?;
+        self.buffer.truncate(read);
+        self.buffer_start = self.position;
+        Ok(())
+    }
+}
+
+impl<T: Read + Seek> Read for CoalescingReader<T> {
+    fn read(&mut self, out: &mut [u8]) -> IoResult<usize> {
+        if self.position < self.buffer_start || self.position >= self.buffer_end() {
+            self.refill()?;
+        }
+        if self.buffer.is_empty() {
+            return Ok(0);
+        }
+        let offset = (self.position - self.buffer_start) as usize;
+        let available = &self.buffer[offset..];
+        let n = available.len().min(out.len());
+        out[..n].copy_from_slice(&available[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Read + Seek> Seek for CoalescingReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        self.position = match pos {
+            SeekFrom::Start(offset) => offset,
+            SeekFrom::Current(offset) => (self.position as i64 + offset) as u64,
+            SeekFrom::End(_) => self.inner.seek(pos)?,
+        };
+        Ok(self.position)
+    }
+}
@@ -0,0 +1,78 @@
+//! Copying a chosen subset of elements from one `.omf` file into a new one, without decoding and
+//! re-encoding the arrays they reference.
+
+use std::collections::HashSet;
+use std::io::{Read, Seek, Write};
+
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+use super::PROJECT_JSON_NAME;
+
+/// Copies the elements at `element_indices` (and only the arrays they reference, transferring
+/// their compressed bytes directly) from `source` into a new project written to `target`.
+/// Everything else about the project (`name`, `description`, `metadata`) is carried over
+/// unchanged; only `elements` is replaced, with the chosen elements in the order given.
+///
+/// Useful for stripping a large project down to the handful of elements a downstream consumer
+/// actually needs, e.g. sending a contractor two surfaces out of a 20 GB block model delivery.
+pub fn extract<R: Read + Seek, W: Write + Seek>(
+    source: R,
+    element_indices: &[usize],
+    target: W,
+) -> Result<W> {
+    let mut archive = zip::ZipArchive::new(source)?;
+
+    let mut json = String::new();
+    archive.by_name(PROJECT_JSON_NAME)?.read_to_string(&mut json)?;
+    let mut project: Value = serde_json::from_str(&json)?;
+    let elements = project
+        .get_mut("elements")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| Error::InvalidFile("source has no \"elements\" array".to_string()))?;
+
+    let selected: Vec<Value> = element_indices
+        .iter()
+        .map(|&i| elements.get(i).cloned().ok_or_else(|| Error::InvalidFile(format!("no element at index {i}"))))
+        .collect::<Result<_>>()?;
+
+    let array_names: HashSet<String> =
+        (0..archive.len()).filter_map(|i| archive.name_for_index(i)).map(str::to_string).collect();
+    let mut referenced = HashSet::new();
+    for element in &selected {
+        collect_referenced_arrays(element, &array_names, &mut referenced);
+    }
+
+    let mut zip = zip::ZipWriter::new(target);
+    for i in 0..archive.len() {
+        let name = archive.name_for_index(i).unwrap_or_default().to_string();
+        if !referenced.contains(&name) {
+            continue;
+        }
+        let entry = archive.by_index_raw(i)?;
+        zip.raw_copy_file(entry)?;
+    }
+
+    project["elements"] = Value::Array(selected);
+    let json = serde_json::to_vec(&project)?;
+    zip.start_file(PROJECT_JSON_NAME, zip::write::FileOptions::<()>::default())?;
+    zip.write_all(&json)?;
+    Ok(zip.finish()?)
+}
+
+/// Recursively collects every string value in `value` that exactly matches a name in
+/// `array_names` — array references are always exact-match strings, the same reasoning
+/// `merge` (see [`super`]) uses when rewriting them.
+fn collect_referenced_arrays(value: &Value, array_names: &HashSet<String>, referenced: &mut HashSet<String>) {
+    match value {
+        Value::String(s) => {
+            if array_names.contains(s) {
+                referenced.insert(s.clone());
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_referenced_arrays(item, array_names, referenced)),
+        Value::Object(map) => map.values().for_each(|item| collect_referenced_arrays(item, array_names, referenced)),
+        _ => {}
+    }
+}
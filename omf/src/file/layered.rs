@@ -0,0 +1,80 @@
+//! Combining a base OMF file with one or more patch overlays into one logical project, without
+//! writing a merged file to disk.
+
+use std::io::{Read, Seek};
+
+use crate::{Project, Result};
+
+use super::{ArrayInfo, Reader};
+
+/// A read-only view combining a base [`Project`] with one or more patch overlays, presenting the
+/// merged result without materializing a combined `.omf` file. Any ordinary `.omf` file can serve
+/// as a layer; there's no separate "patch" file format, just the convention that a later layer's
+/// elements take priority over an earlier one's.
+///
+/// Layers are ordered oldest (the base) to newest (the last patch). Enables fast what-if
+/// comparisons (open a base file plus a candidate patch and inspect the merged project without
+/// writing anything) and incremental viewing workflows (apply patches one at a time and always
+/// see the up-to-date merged result).
+pub struct LayeredReader<R> {
+    layers: Vec<Reader<R>>,
+}
+
+impl<R: Read + Seek> LayeredReader<R> {
+    /// Wraps `layers`, ordered oldest (the base) to newest (the last patch). At least one layer
+    /// is required.
+    pub fn new(layers: Vec<Reader<R>>) -> Result<Self> {
+        if layers.is_empty() {
+            return Err(crate::Error::InvalidFile("LayeredReader requires at least one layer".to_string()));
+        }
+        Ok(Self { layers })
+    }
+
+    /// Merges every layer's project into one logical [`Project`]: elements are matched by name,
+    /// and where more than one layer defines an element with that name, the newest layer's
+    /// definition replaces the older one outright rather than being merged field-by-field. New
+    /// elements from later layers are appended in the order they're first seen. Metadata is
+    /// merged key-by-key, newest layer winning on conflicts.
+    pub fn project(&mut self) -> Result<Project> {
+        let mut merged: Option<Project> = None;
+        for reader in &mut self.layers {
+            let layer = reader.project()?;
+            merged = Some(match merged {
+                None => layer,
+                Some(mut base) => {
+                    for element in layer.elements {
+                        match base.elements.iter_mut().find(|e| e.name == element.name) {
+                            Some(existing) => *existing = element,
+                            None => base.elements.push(element),
+                        }
+                    }
+                    base.metadata.extend(layer.metadata);
+                    base
+                }
+            });
+        }
+        Ok(merged.expect("at least one layer, checked in LayeredReader::new"))
+    }
+
+    /// Finds the newest layer (searching last to first) that has an array named `name`, without
+    /// reading its contents.
+    fn newest_layer_with_array(&mut self, name: &str) -> Option<usize> {
+        self.layers
+            .iter_mut()
+            .enumerate()
+            .rev()
+            .find(|(_, reader)| reader.list_arrays().iter().any(|array: &ArrayInfo| array.name == name))
+            .map(|(index, _)| index)
+    }
+
+    /// Reads a named array of any [`bytemuck::Pod`] element type, resolving it from the newest
+    /// layer that has an array with that name (see [`Reader::read_array_of`]). Returns `None` if
+    /// no layer has it.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_array_of<T: bytemuck::Pod>(&mut self, name: &str) -> Result<Option<Vec<T>>> {
+        match self.newest_layer_with_array(name) {
+            Some(index) => Ok(Some(self.layers[index].read_array_of(name)?)),
+            None => Ok(None),
+        }
+    }
+}
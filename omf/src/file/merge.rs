@@ -0,0 +1,198 @@
+//! Combining several `.omf` files into one project, without decoding and re-encoding their
+//! arrays.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+use super::PROJECT_JSON_NAME;
+
+/// Copies every element from each of `sources`, in order, into one project written to `target`,
+/// raw-copying each source's array members' compressed bytes directly instead of decoding and
+/// re-encoding them. Useful for combining survey, geology, and block model files produced
+/// separately without round-tripping every array through memory.
+///
+/// Two kinds of name collisions between sources are resolved automatically:
+/// - Elements: a later element whose name collides with one already added is renamed by
+///   appending " (2)", " (3)", ... .
+/// - Arrays: since every source numbers its own arrays independently (e.g. `"0-vertices"`),
+///   every array is renamed with a `"src{index}-"` prefix before being copied, and the same
+///   rename is applied everywhere that name appears in that source's elements, so geometry and
+///   attribute references stay correct.
+///
+/// The merged project takes its `name`, `description`, and `metadata` from the first source;
+/// only its `elements` list is replaced.
+pub fn merge<R: Read + Seek, W: Write + Seek>(sources: Vec<R>, target: W) -> Result<W> {
+    let mut zip = zip::ZipWriter::new(target);
+    let mut merged_project: Option<Value> = None;
+    let mut merged_elements = Vec::new();
+    let mut used_element_names = std::collections::HashSet::new();
+
+    for (source_index, source) in sources.into_iter().enumerate() {
+        let mut archive = zip::ZipArchive::new(source)?;
+
+        let mut json = String::new();
+        archive.by_name(PROJECT_JSON_NAME)?.read_to_string(&mut json)?;
+        let mut project: Value = serde_json::from_str(&json)?;
+        let elements = project
+            .get_mut("elements")
+            .and_then(Value::as_array_mut)
+            .ok_or_else(|| Error::InvalidFile(format!("source {source_index} has no \"elements\" array")))?;
+
+        let rename: HashMap<String, String> = (0..archive.len())
+            .filter_map(|i| archive.name_for_index(i))
+            .filter(|name| *name != PROJECT_JSON_NAME)
+            .map(|name| (name.to_string(), format!("src{source_index}-{name}")))
+            .collect();
+
+        for element in elements.iter_mut() {
+            rename_array_references(element, &rename);
+            if let Some(Value::String(name)) = element.get_mut("name") {
+                *name = unique_name(&used_element_names, name.clone());
+            }
+            if let Some(name) = element.get("name").and_then(Value::as_str) {
+                used_element_names.insert(name.to_string());
+            }
+            merged_elements.push(element.take());
+        }
+
+        for i in 0..archive.len() {
+            let name = archive.name_for_index(i).unwrap_or_default().to_string();
+            if name == PROJECT_JSON_NAME {
+                continue;
+            }
+            let entry = archive.by_index_raw(i)?;
+            zip.raw_copy_file_rename(entry, &rename[&name])?;
+        }
+
+        if merged_project.is_none() {
+            merged_project = Some(project);
+        }
+    }
+
+    let mut merged_project = merged_project
+        .ok_or_else(|| Error::InvalidFile("merge requires at least one source".to_string()))?;
+    merged_project["elements"] = Value::Array(merged_elements);
+    let json = serde_json::to_vec(&merged_project)?;
+    zip.start_file(PROJECT_JSON_NAME, zip::write::FileOptions::<()>::default())?;
+    zip.write_all(&json)?;
+    Ok(zip.finish()?)
+}
+
+/// Recursively replaces every string value in `value` that exactly matches a key in `rename`
+/// with its renamed counterpart. Array name references (vertices, triangles, attribute values,
+/// texture bytes, ...) always appear as exact-match strings, so this catches every reference
+/// without needing to know each field's specific meaning.
+fn rename_array_references(value: &mut Value, rename: &HashMap<String, String>) {
+    match value {
+        Value::String(s) => {
+            if let Some(renamed) = rename.get(s.as_str()) {
+                *s = renamed.clone();
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| rename_array_references(item, rename)),
+        Value::Object(map) => map.values_mut().for_each(|item| rename_array_references(item, rename)),
+        _ => {}
+    }
+}
+
+/// Appends " (2)", " (3)", ... to `name` until it no longer collides with anything in `used`.
+fn unique_name(used: &std::collections::HashSet<String>, name: String) -> String {
+    if !used.contains(&name) {
+        return name;
+    }
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{name} ({suffix})");
+        if !used.contains(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::io::Cursor;
+
+    use crate::file::{Reader, Writer};
+    use crate::geometry::{Geometry, PointSet};
+    use crate::{Element, Project};
+
+    use super::*;
+
+    #[test]
+    fn unique_name_appends_a_suffix_only_on_collision() {
+        let mut used = HashSet::new();
+        used.insert("Points".to_string());
+        assert_eq!(unique_name(&used, "Points".to_string()), "Points (2)");
+        used.insert("Points (2)".to_string());
+        assert_eq!(unique_name(&used, "Points".to_string()), "Points (3)");
+        assert_eq!(unique_name(&used, "Other".to_string()), "Other");
+    }
+
+    #[test]
+    fn rename_array_references_replaces_exact_string_matches_anywhere() {
+        let rename: HashMap<String, String> = [("vertices".to_string(), "src0-vertices".to_string())].into();
+        let mut value = serde_json::json!({
+            "geometry": {"type": "PointSet", "vertices": "vertices"},
+            "attributes": [{"data": {"values": "vertices"}}],
+            "unrelated": "vertices-ish",
+        });
+        rename_array_references(&mut value, &rename);
+        assert_eq!(value["geometry"]["vertices"], "src0-vertices");
+        assert_eq!(value["attributes"][0]["data"]["values"], "src0-vertices");
+        assert_eq!(value["unrelated"], "vertices-ish", "must only replace exact matches");
+    }
+
+    /// Writes a minimal one-element point set project to an in-memory `.omf` file, for feeding
+    /// into [`merge`] as a source.
+    fn point_set_source(element_name: &str, array_name: &str, vertices: &[[f64; 3]]) -> Vec<u8> {
+        let mut writer = Writer::new_in_memory().unwrap();
+        writer.write_array(array_name, bytemuck::cast_slice(vertices)).unwrap();
+        let mut project = Project::new("source");
+        project.elements.push(Element {
+            id: String::new(),
+            name: element_name.to_string(),
+            description: String::new(),
+            geometry: Geometry::PointSet(PointSet { vertices: array_name.to_string(), origin: [0.0; 3] }),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        let (bytes, ..) = writer.finish(&project).unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn merge_deduplicates_colliding_element_and_array_names_into_a_readable_file() {
+        // Both sources use the same element name and the same internal array name, exactly the
+        // collision `merge`'s doc comment says it resolves.
+        let source_a = point_set_source("Points", "vertices", &[[0.0, 0.0, 0.0]]);
+        let source_b = point_set_source("Points", "vertices", &[[1.0, 2.0, 3.0]]);
+
+        let merged_bytes = merge(vec![Cursor::new(source_a), Cursor::new(source_b)], Cursor::new(Vec::new()))
+            .unwrap()
+            .into_inner();
+
+        let mut reader = Reader::new(Cursor::new(merged_bytes)).unwrap();
+        let project = reader.project().unwrap();
+        assert_eq!(project.elements.len(), 2);
+        assert_eq!(project.elements[0].name, "Points");
+        assert_eq!(project.elements[1].name, "Points (2)");
+
+        let Geometry::PointSet(first) = &project.elements[0].geometry else { panic!("expected a point set") };
+        let Geometry::PointSet(second) = &project.elements[1].geometry else { panic!("expected a point set") };
+        assert_ne!(first.vertices, second.vertices, "colliding array names must be renamed apart");
+
+        let first_vertices: Vec<[f64; 3]> = reader.read_array_of(&first.vertices).unwrap();
+        let second_vertices: Vec<[f64; 3]> = reader.read_array_of(&second.vertices).unwrap();
+        assert_eq!(first_vertices, vec![[0.0, 0.0, 0.0]]);
+        assert_eq!(second_vertices, vec![[1.0, 2.0, 3.0]]);
+    }
+}
@@ -0,0 +1,51 @@
+//! Reading and writing of the on-disk OMF zip container.
+
+#[cfg(not(feature = "zip-read-only"))]
+mod benchmark;
+mod coalescing;
+#[cfg(not(feature = "zip-read-only"))]
+mod extract;
+mod layered;
+#[cfg(not(feature = "zip-read-only"))]
+mod merge;
+#[cfg(all(
+    feature = "parallel",
+    any(feature = "parquet", feature = "parquet-read"),
+    not(target_arch = "wasm32")
+))]
+mod parallel;
+mod reader;
+#[cfg(not(feature = "zip-read-only"))]
+mod recompress;
+mod strict_fields;
+mod sub_file;
+mod summary;
+mod warnings;
+#[cfg(not(feature = "zip-read-only"))]
+mod writer;
+
+#[cfg(not(feature = "zip-read-only"))]
+pub use benchmark::{ArrayTiming, BenchmarkReport};
+pub use coalescing::CoalescingReader;
+#[cfg(not(feature = "zip-read-only"))]
+pub use extract::extract;
+pub use layered::LayeredReader;
+#[cfg(not(feature = "zip-read-only"))]
+pub use merge::merge;
+#[cfg(all(
+    feature = "parallel",
+    any(feature = "parquet", feature = "parquet-read"),
+    not(target_arch = "wasm32")
+))]
+pub use parallel::ParallelArrayReader;
+pub use reader::{ArrayInfo, ElementSummary, NumberChunks, ProjectSummary, Reader};
+#[cfg(not(feature = "zip-read-only"))]
+pub use recompress::{recompress, CompressionMethod};
+pub use sub_file::SubFile;
+pub use summary::{summary_of, ArraySummary, ARRAY_SUMMARY_METADATA_KEY};
+pub use warnings::{group_warnings, Warning, WarningGroup};
+#[cfg(not(feature = "zip-read-only"))]
+pub use writer::Writer;
+
+/// Name of the project JSON entry within the zip container.
+pub(crate) const PROJECT_JSON_NAME: &str = "project.json";
@@ -0,0 +1,65 @@
+//! Parallel array reads, available when the crate's `parallel` feature is enabled.
+//!
+//! [`Reader`] decodes through one shared `zip::ZipArchive`, so its array reads are inherently
+//! sequential even when a caller only wants the values, not any particular order. [`ParallelArrayReader`]
+//! instead keeps a cheaply-clonable handle to the underlying bytes and reopens an independent
+//! `zip::ZipArchive` per array, letting rayon workers decompress several arrays at once — useful
+//! for a desktop importer loading a project with hundreds of attribute arrays that wants to
+//! saturate all cores instead of taking turns through one archive.
+
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::Result;
+
+/// A memory-mapped file wrapped in an `Arc` so it can be cheaply cloned into one
+/// `std::io::Cursor` per thread, all backed by the same mapping.
+#[derive(Clone)]
+struct SharedMmap(Arc<memmap2::Mmap>);
+
+impl AsRef<[u8]> for SharedMmap {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Reads several of an `.omf` file's array members concurrently. Unlike [`super::Reader`], which
+/// holds one `zip::ZipArchive` that reads must take turns through, this reopens the archive once
+/// per array against a shared memory mapping, so decompression genuinely runs in parallel.
+///
+/// Only handles raw array bytes: use [`super::Reader`] for `project.json` and typed decoding
+/// (e.g. [`super::Reader::read_array_of`]'s `bytemuck` cast), casting the bytes this type returns
+/// the same way.
+pub struct ParallelArrayReader {
+    source: SharedMmap,
+}
+
+impl ParallelArrayReader {
+    /// Memory-maps the `.omf` file at `path`.
+    ///
+    /// # Safety caveat
+    /// As with any `mmap`, the file must not be modified by another process while mapped, or
+    /// reads may observe torn data.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self { source: SharedMmap(Arc::new(mmap)) })
+    }
+
+    /// Reads each named array's raw decompressed bytes, in the same order as `names`, decoding
+    /// concurrently across up to `rayon::current_num_threads()` threads.
+    pub fn read_arrays(&self, names: &[String]) -> Result<Vec<Vec<u8>>> {
+        names
+            .par_iter()
+            .map(|name| {
+                let mut archive = zip::ZipArchive::new(Cursor::new(self.source.clone()))?;
+                let mut entry = archive.by_name(name)?;
+                let mut bytes = Vec::with_capacity(entry.size() as usize);
+                entry.read_to_end(&mut bytes)?;
+                Ok(bytes)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,899 @@
+use std::fs::File;
+use std::io::{Read as _, Seek};
+
+use serde_json::Value;
+
+use crate::problem::{ProblemCollector, Problems, Reason, ValidationOptions};
+use crate::{Limits, Project, Result};
+
+use super::{group_warnings, Warning, WarningGroup, PROJECT_JSON_NAME};
+
+/// Reads a [`Project`] and its associated arrays out of an `.omf` file.
+pub struct Reader<R> {
+    archive: zip::ZipArchive<R>,
+    limits: Limits,
+    strict_types: bool,
+    strict_fields: bool,
+    lenient_encoding: bool,
+    pinned_version: Option<String>,
+    validation_options: ValidationOptions,
+}
+
+impl Reader<File> {
+    /// Opens the `.omf` file at `path` with the default [`Limits`].
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::new(File::open(path)?)
+    }
+}
+
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+impl Reader<std::io::Cursor<memmap2::Mmap>> {
+    /// Opens the `.omf` file at `path` as a memory-mapped [`Reader`]. Workflows that seek across
+    /// many small arrays (grid tensors, category legends) benefit from mmap avoiding per-seek
+    /// syscall overhead, and multiple `Reader`s can cheaply share the same mapping.
+    ///
+    /// # Safety concerns
+    ///
+    /// As with any use of `mmap`, the file must not be modified by another process while it's
+    /// mapped; doing so is undefined behavior in the underlying `memmap2` crate.
+    pub fn open_mmap(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: caller's responsibility per the doc comment above.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Self::new(std::io::Cursor::new(mmap))
+    }
+}
+
+impl<R: std::io::Read + Seek> Reader<R> {
+    /// Wraps an existing reader with the default [`Limits`].
+    pub fn new(source: R) -> Result<Self> {
+        Self::with_limits(source, Limits::default())
+    }
+
+    /// Wraps an existing reader, applying the given [`Limits`] while reading.
+    pub fn with_limits(source: R, limits: Limits) -> Result<Self> {
+        let archive = zip::ZipArchive::new(source)?;
+        Ok(Self {
+            archive,
+            limits,
+            strict_types: false,
+            strict_fields: false,
+            lenient_encoding: false,
+            pinned_version: None,
+            validation_options: ValidationOptions::default(),
+        })
+    }
+
+    /// Sets the [`ValidationOptions`] applied by [`Reader::validate`]. Defaults to
+    /// [`ValidationOptions::default`], which treats every [`crate::problem::Reason`] as its
+    /// [`crate::problem::Reason::default_severity`] with no per-category limit.
+    pub fn with_validation_options(mut self, options: ValidationOptions) -> Self {
+        self.validation_options = options;
+        self
+    }
+
+    /// If `strict` is true, [`Reader::project`] rejects files containing an attribute data type
+    /// this version of the crate doesn't recognize instead of loading it as
+    /// [`crate::AttributeData::Unknown`]. Off by default: unknown attributes are lenient so that
+    /// files from newer writers still mostly load.
+    pub fn with_strict_types(mut self, strict: bool) -> Self {
+        self.strict_types = strict;
+        self
+    }
+
+    /// If `strict` is true, [`Reader::validate`] additionally runs
+    /// [`Reader::unrecognized_field_warnings`] and reports any hit as a
+    /// [`crate::problem::Reason::UnrecognizedField`] problem. Off by default, since it costs a
+    /// second parse-and-reserialize pass over `project.json` on top of the normal one. This crate
+    /// has no generated JSON schema to validate against (see
+    /// [`Reader::unrecognized_field_warnings`]'s doc comment for why that's not needed here).
+    pub fn with_strict_fields(mut self, strict: bool) -> Self {
+        self.strict_fields = strict;
+        self
+    }
+
+    /// If `lenient` is true, a `project.json` that isn't valid UTF-8 is decoded as Latin-1
+    /// (byte-for-byte, since every byte value is a valid Latin-1 code point) instead of failing
+    /// outright. Off by default. Some C++ writers emit unescaped Latin-1 bytes in string fields,
+    /// which violates the JSON spec but is otherwise a well-formed file; turning this on trades
+    /// strictness for the ability to open (and, via [`crate::repair`], fix) such files. Use
+    /// [`Reader::project_with_warnings`] to find out when this fallback was used.
+    pub fn with_lenient_encoding(mut self, lenient: bool) -> Self {
+        self.lenient_encoding = lenient;
+        self
+    }
+
+    /// Requires [`Reader::project`] to reject any file whose [`crate::Project::version`] isn't
+    /// exactly `version`, and to reject prerelease versions outright (see
+    /// [`crate::project::is_prerelease_version`]) even if they happen to match. `None` (the
+    /// default) accepts any non-prerelease version this crate can otherwise parse.
+    ///
+    /// For regulated reporting chains that must guarantee every file conforms to one approved
+    /// specification revision, rather than "whatever this version of the crate happens to
+    /// accept."
+    pub fn with_pinned_version(mut self, version: Option<String>) -> Self {
+        self.pinned_version = version;
+        self
+    }
+
+    /// Reads and parses the project JSON, validating it against `self.limits`.
+    pub fn project(&mut self) -> Result<Project> {
+        let (project, _lossy_bytes) = self.project_and_lossy_bytes()?;
+        Ok(project)
+    }
+
+    /// Shared implementation behind [`Reader::project`] and [`Reader::project_with_warnings`]:
+    /// parses the project JSON and enforces [`Reader::with_strict_types`], additionally reporting
+    /// how many bytes [`Reader::with_lenient_encoding`] had to reinterpret, if any.
+    fn project_and_lossy_bytes(&mut self) -> Result<(Project, Option<usize>)> {
+        let (text, lossy_bytes) = self.read_project_text()?;
+        let project: Project = serde_json::from_str(&text)?;
+        if project.elements.len() > self.limits.max_elements {
+            return Err(crate::Error::LimitExceeded(format!(
+                "project has {} elements, limit is {}",
+                project.elements.len(),
+                self.limits.max_elements
+            )));
+        }
+        for element in &project.elements {
+            if element.attributes.len() > self.limits.max_attributes_per_element {
+                return Err(crate::Error::LimitExceeded(format!(
+                    "element \"{}\" has {} attributes, limit is {}",
+                    element.name,
+                    element.attributes.len(),
+                    self.limits.max_attributes_per_element
+                )));
+            }
+        }
+        if let Some(pinned) = &self.pinned_version {
+            if crate::project::is_prerelease_version(&project.version) {
+                return Err(crate::Error::InvalidFile(format!(
+                    "project is a prerelease format version \"{}\", refused by Reader::with_pinned_version",
+                    project.version
+                )));
+            }
+            if &project.version != pinned {
+                return Err(crate::Error::InvalidFile(format!(
+                    "project is format version \"{}\", but Reader::with_pinned_version requires exactly \"{pinned}\"",
+                    project.version
+                )));
+            }
+        }
+        if self.strict_types {
+            for element in &project.elements {
+                for attribute in &element.attributes {
+                    if attribute.data.is_unknown() {
+                        return Err(crate::Error::InvalidFile(format!(
+                            "attribute \"{}\" on element \"{}\" has an unrecognized data type",
+                            attribute.name, element.name
+                        )));
+                    }
+                }
+            }
+        }
+        Ok((project, lossy_bytes))
+    }
+
+    /// Like [`Reader::project`], but also returns deduplicated, grouped warnings about
+    /// non-fatal problems noticed while reading, e.g. attributes referencing an array that isn't
+    /// in the file. A file with many repeats of the same problem reports one [`WarningGroup`]
+    /// instead of one [`Warning`] per occurrence.
+    pub fn project_with_warnings(&mut self) -> Result<(Project, Vec<WarningGroup>)> {
+        self.project_with_warning_groups(5)
+    }
+
+    /// Body of [`Reader::project_with_warnings`], keeping up to `examples_per_group` messages
+    /// per group.
+    fn project_with_warning_groups(&mut self, examples_per_group: usize) -> Result<(Project, Vec<WarningGroup>)> {
+        let (project, lossy_bytes) = self.project_and_lossy_bytes()?;
+        let mut warnings = Vec::new();
+        if let Some(byte_count) = lossy_bytes {
+            warnings.push(Warning {
+                category: "non_utf8_project_json".to_string(),
+                message: format!(
+                    "project.json was not valid UTF-8 ({byte_count} byte(s)); decoded leniently as Latin-1"
+                ),
+            });
+        }
+        for element in &project.elements {
+            for attribute in &element.attributes {
+                if attribute.data.is_unknown() {
+                    warnings.push(Warning {
+                        category: "unknown_attribute_type".to_string(),
+                        message: format!(
+                            "attribute \"{}\" on element \"{}\" has an unrecognized data type",
+                            attribute.name, element.name
+                        ),
+                    });
+                }
+                if let Some(units) = crate::units::units_of(attribute) {
+                    if !crate::units::is_known_unit(&units) {
+                        warnings.push(Warning {
+                            category: "unrecognized_units".to_string(),
+                            message: format!(
+                                "attribute \"{}\" on element \"{}\" has unrecognized units \"{units}\"",
+                                attribute.name, element.name
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        Ok((project, group_warnings(&warnings, examples_per_group)))
+    }
+
+    /// Reports every JSON object field in `project.json` that isn't recognized by this version of
+    /// the crate: present in the raw document but dropped when it's parsed into [`Project`] and
+    /// re-serialized. Requires re-parsing `project.json` a second time, so it's opt-in (see
+    /// [`Reader::with_strict_fields`]) rather than run on every [`Reader::project`] call.
+    ///
+    /// This crate has no generated JSON schema to validate `project.json` against; comparing it to
+    /// its own round trip catches the same class of producer bug (a typo'd or newer-version field
+    /// name) without needing one. A field this crate *does* recognize but with the wrong JSON type
+    /// already fails the initial parse outright, so there's nothing left for this check to catch
+    /// on that front.
+    pub fn unrecognized_field_warnings(&mut self) -> Result<Vec<Warning>> {
+        let (text, _lossy_bytes) = self.read_project_text()?;
+        let raw: Value = serde_json::from_str(&text)?;
+        let project: Project = serde_json::from_str(&text)?;
+        let canonical = serde_json::to_value(&project)?;
+        let mut paths = Vec::new();
+        super::strict_fields::find_unrecognized_fields(&raw, &canonical, "", &mut paths);
+        Ok(paths
+            .into_iter()
+            .map(|path| Warning {
+                category: "unrecognized_field".to_string(),
+                message: format!("project.json field \"{path}\" isn't recognized by this version of the crate"),
+            })
+            .collect())
+    }
+
+    /// Like [`Reader::project_with_warnings`], but reports every problem as a [`Problem`] with a
+    /// stable [`Reason`] code and severity, filtered and capped by
+    /// [`Reader::with_validation_options`], and additionally runs
+    /// [`Reader::texcoord_warnings`]/[`Reader::sparse_attribute_warnings`]/
+    /// [`Reader::topology_warnings`], and, if [`Reader::with_strict_fields`] is set,
+    /// [`Reader::unrecognized_field_warnings`], unless
+    /// [`ValidationOptions::skip_expensive_checks`] is set. Fails outright if any collected
+    /// problem's effective severity is [`crate::problem::Severity::Error`] (only possible via
+    /// [`ValidationOptions::warnings_as_errors`] or an explicit
+    /// [`ValidationOptions::severity_overrides`] entry, since every reason this checks defaults
+    /// to a warning).
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn validate(&mut self) -> Result<(Project, Problems)> {
+        let (project, warning_groups) = self.project_with_warning_groups(usize::MAX)?;
+        let mut collector = ProblemCollector::new(self.validation_options.clone());
+        for group in &warning_groups {
+            let reason = match group.category.as_str() {
+                "non_utf8_project_json" => Reason::NonUtf8ProjectJson,
+                "unknown_attribute_type" => Reason::UnknownAttributeType,
+                "unrecognized_units" => Reason::UnrecognizedUnits,
+                _ => continue,
+            };
+            for example in &group.examples {
+                collector.record(reason, example.clone());
+            }
+        }
+        if !self.validation_options.skip_expensive_checks {
+            for warning in self.texcoord_warnings(&project)? {
+                collector.record(Reason::SuspiciousTexcoordRange, warning.message);
+            }
+            for warning in self.sparse_attribute_warnings(&project)? {
+                collector.record(Reason::MostlyNullAttribute, warning.message);
+            }
+            for warning in self.topology_warnings(&project)? {
+                collector.record(Reason::InvalidTopology, warning.message);
+            }
+            if self.strict_fields {
+                for warning in self.unrecognized_field_warnings()? {
+                    collector.record(Reason::UnrecognizedField, warning.message);
+                }
+            }
+        }
+        if collector.has_errors() {
+            let problems = collector.into_problems();
+            let messages: Vec<&str> =
+                problems.iter().filter(|p| p.severity == crate::problem::Severity::Error).map(|p| p.message.as_str()).collect();
+            return Err(crate::Error::Validation(messages.join("; ")));
+        }
+        Ok((project, collector.into_problems()))
+    }
+
+    /// The project's overall [`crate::BoundingBox`], if `project` has one recorded (see
+    /// [`Writer::with_bounding_boxes`](super::Writer::with_bounding_boxes)), without needing to
+    /// decode a single vertex or block array.
+    pub fn project_bounding_box(&self, project: &Project) -> Option<crate::BoundingBox> {
+        project.bounding_box
+    }
+
+    /// The [`crate::BoundingBox`] recorded on element `element_index` of `project`, if any, or
+    /// `None` if the index is out of range or the element has no recorded box. See
+    /// [`Reader::project_bounding_box`] for the whole project's extent.
+    pub fn element_bounding_box(&self, project: &Project, element_index: usize) -> Option<crate::BoundingBox> {
+        project.elements.get(element_index)?.bounding_box
+    }
+
+    /// A lightweight structural overview of the project: its name, version, top-level metadata
+    /// keys, and one [`ElementSummary`] per element. Unlike [`Reader::project`], this parses
+    /// `project.json` as generic JSON and reads only the fields listed above, so it never
+    /// constructs an [`crate::Attribute`], [`crate::Geometry`], or any other typed data-model
+    /// value, and skips [`Reader::with_strict_types`] validation entirely.
+    ///
+    /// Intended for file browsers and catalog tools that list many large projects (thousands of
+    /// elements each) and only need to show an outline before the user picks something to inspect
+    /// with the full [`Reader::project`].
+    pub fn project_summary(&mut self) -> Result<ProjectSummary> {
+        let (text, _lossy_bytes) = self.read_project_text()?;
+        let value: Value = serde_json::from_str(&text)?;
+        let name = value.get("name").and_then(Value::as_str).unwrap_or_default().to_string();
+        let version = value
+            .get("version")
+            .and_then(Value::as_str)
+            .unwrap_or(crate::project::CURRENT_VERSION)
+            .to_string();
+        let metadata_keys = object_keys(value.get("metadata"));
+        let elements = value
+            .get("elements")
+            .and_then(Value::as_array)
+            .map(|elements| elements.iter().map(element_summary_of).collect())
+            .unwrap_or_default();
+        Ok(ProjectSummary { name, version, metadata_keys, elements })
+    }
+
+    /// Returns `project.json`'s contents as a string, exactly as stored in the file (or decoded
+    /// via [`Reader::with_lenient_encoding`]), for an integrator writing an importer in another
+    /// language that wants the raw index document instead of going through this crate's own
+    /// [`Project`] type. Pass `pretty` to reformat it with two-space indentation first, e.g. for
+    /// display in a log or a debugging tool; `false` returns the bytes as originally written,
+    /// whatever their formatting.
+    ///
+    /// This crate has no generated JSON schema for `project.json` to hand out alongside it; see
+    /// [`Reader::unrecognized_field_warnings`] for how strict-mode field checking gets by without
+    /// one.
+    pub fn project_json(&mut self, pretty: bool) -> Result<String> {
+        let (text, _lossy_bytes) = self.read_project_text()?;
+        if !pretty {
+            return Ok(text);
+        }
+        let value: Value = serde_json::from_str(&text)?;
+        Ok(serde_json::to_string_pretty(&value)?)
+    }
+
+    /// Reads the raw `project.json` entry, validating it against `self.limits`, and decodes it to
+    /// text. Returns the number of bytes that had to be reinterpreted if `self.lenient_encoding`
+    /// was used to recover from invalid UTF-8, or `None` if the bytes were already valid UTF-8.
+    fn read_project_text(&mut self) -> Result<(String, Option<usize>)> {
+        let mut entry = self.archive.by_name(PROJECT_JSON_NAME)?;
+        if entry.size() > self.limits.json_bytes {
+            return Err(crate::Error::LimitExceeded(format!(
+                "project.json is {} bytes, limit is {}",
+                entry.size(),
+                self.limits.json_bytes
+            )));
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok((text, None)),
+            Err(error) if self.lenient_encoding => {
+                let bytes = error.into_bytes();
+                let byte_count = bytes.len();
+                Ok((decode_latin1(&bytes), Some(byte_count)))
+            }
+            Err(error) => Err(crate::Error::InvalidFile(format!("project.json is not valid UTF-8: {error}"))),
+        }
+    }
+
+    /// Lists every array member stored in the file, with its name and compressed size on disk,
+    /// without decoding any array contents. Available even when the crate is built without the
+    /// `parquet` feature, so lightweight tooling (catalog indexers, web metadata scrapers) that
+    /// only cares about a file's structure can avoid pulling in the Parquet/Arrow dependency
+    /// tree entirely.
+    pub fn list_arrays(&mut self) -> Vec<ArrayInfo> {
+        let mut arrays = Vec::new();
+        for i in 0..self.archive.len() {
+            // `by_index_raw` reads the local file header, not the compressed contents, so this
+            // stays cheap even for a multi-gigabyte array.
+            if let Ok(entry) = self.archive.by_index_raw(i) {
+                let name = entry.name().to_string();
+                if name != PROJECT_JSON_NAME {
+                    arrays.push(ArrayInfo { name, byte_size: entry.compressed_size() });
+                }
+            }
+        }
+        arrays
+    }
+
+    /// Reads a named array of `[f64; 3]` values, e.g. a geometry's vertex positions.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_array_f64x3(&mut self, name: &str) -> Result<Vec<[f64; 3]>> {
+        self.read_array_of(name)
+    }
+
+    /// Reads a named array of `[u32; 3]` values, e.g. a surface's triangle indices.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_array_u32x3(&mut self, name: &str) -> Result<Vec<[u32; 3]>> {
+        self.read_array_of(name)
+    }
+
+    /// Reads a [`crate::geometry::Surface`]'s triangle indices, widening them to `[u64; 3]` if
+    /// stored as `[u32; 3]` so callers don't need to branch on [`crate::geometry::Surface::wide_indices`]
+    /// themselves.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_triangles(&mut self, surface: &crate::geometry::Surface) -> Result<Vec<[u64; 3]>> {
+        if surface.wide_indices {
+            self.read_array_of(&surface.triangles)
+        } else {
+            let narrow: Vec<[u32; 3]> = self.read_array_of(&surface.triangles)?;
+            Ok(narrow.into_iter().map(|t| [t[0] as u64, t[1] as u64, t[2] as u64]).collect())
+        }
+    }
+
+    /// Reads a [`crate::geometry::LineSet`]'s segment indices, widening them to `[u64; 2]` if
+    /// stored as `[u32; 2]` so callers don't need to branch on [`crate::geometry::LineSet::wide_indices`]
+    /// themselves.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_segments(&mut self, line_set: &crate::geometry::LineSet) -> Result<Vec<[u64; 2]>> {
+        if line_set.wide_indices {
+            self.read_array_of(&line_set.segments)
+        } else {
+            let narrow: Vec<[u32; 2]> = self.read_array_of(&line_set.segments)?;
+            Ok(narrow.into_iter().map(|s| [s[0] as u64, s[1] as u64]).collect())
+        }
+    }
+
+    /// Reads a [`crate::AttributeData::Texcoord`] attribute's values and reports their `u`/`v`
+    /// bounds, dequantizing first if the attribute stores them as `[u16; 2]`. Returns `None` if
+    /// `attribute` isn't a texcoord attribute.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn texcoord_bounds(
+        &mut self,
+        attribute: &crate::Attribute,
+    ) -> Result<Option<crate::attribute::TexcoordBounds>> {
+        let crate::AttributeData::Texcoord { values, quantized } = &attribute.data else {
+            return Ok(None);
+        };
+        let values: Vec<[f32; 2]> = if *quantized {
+            let quantized: Vec<[u16; 2]> = self.read_array_of(values)?;
+            crate::attribute::dequantize(&quantized)
+        } else {
+            self.read_array_of(values)?
+        };
+        Ok(crate::attribute::TexcoordBounds::compute(&values))
+    }
+
+    /// Checks every texcoord attribute on `project` for `u`/`v` bounds far enough outside `[0,
+    /// 1]` to suggest the coordinates were mapped incorrectly (see
+    /// [`crate::attribute::TexcoordBounds::is_suspicious`]), a common cause of "my texture is a
+    /// smear" bug reports that's otherwise invisible until rendering.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn texcoord_warnings(&mut self, project: &crate::Project) -> Result<Vec<Warning>> {
+        let mut warnings = Vec::new();
+        for element in &project.elements {
+            for attribute in &element.attributes {
+                if let Some(bounds) = self.texcoord_bounds(attribute)? {
+                    if bounds.is_suspicious() {
+                        warnings.push(Warning {
+                            category: "suspicious_texcoord_range".to_string(),
+                            message: format!(
+                                "attribute \"{}\" on element \"{}\" has texcoords ranging from \
+                                 {:?} to {:?}, far outside the expected [0, 1]",
+                                attribute.name, element.name, bounds.min, bounds.max
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// Reads a [`crate::AttributeData::Number`] attribute's values and reports null-fraction and
+    /// longest-null-run statistics over them (see [`crate::attribute::NullRunStats`]). Returns
+    /// `None` if `attribute` isn't a number attribute.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn null_run_stats(
+        &mut self,
+        attribute: &crate::Attribute,
+    ) -> Result<Option<crate::attribute::NullRunStats>> {
+        let crate::AttributeData::Number { values } = &attribute.data else {
+            return Ok(None);
+        };
+        let values: Vec<f64> = self.read_array_of(values)?;
+        Ok(Some(crate::attribute::NullRunStats::compute(&values)))
+    }
+
+    /// Reads `attribute`'s values and reports a null-count and validity summary (see
+    /// [`crate::attribute::AttributeSummary`]) under whichever null convention its
+    /// [`crate::AttributeData`] variant uses: `NaN` for
+    /// [`crate::AttributeData::Number`] (via [`Reader::null_run_stats`]), an index with no
+    /// corresponding category name for [`crate::AttributeData::Category`]. Also reports a distinct
+    /// value count for `Category` and [`crate::AttributeData::Boolean`], which is cheap to derive
+    /// without a full hash-set scan for those two variants (see
+    /// [`crate::attribute::AttributeSummary::distinct_count`]). Returns `None` for a variant with
+    /// no defined null convention (`Text`, `Texcoord`, or an unrecognized type).
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn attribute_summary(
+        &mut self,
+        attribute: &crate::Attribute,
+    ) -> Result<Option<crate::attribute::AttributeSummary>> {
+        match &attribute.data {
+            crate::AttributeData::Number { .. } => {
+                Ok(self.null_run_stats(attribute)?.map(Into::into))
+            }
+            crate::AttributeData::Category { values, names, .. } => {
+                let indices: Vec<u32> = self.read_array_of(values)?;
+                let mut used = vec![false; names.len()];
+                let mut null_count = 0u64;
+                for &index in &indices {
+                    match used.get_mut(index as usize) {
+                        Some(seen) => *seen = true,
+                        None => null_count += 1,
+                    }
+                }
+                Ok(Some(crate::attribute::AttributeSummary {
+                    count: indices.len() as u64,
+                    null_count,
+                    distinct_count: Some(used.into_iter().filter(|&seen| seen).count() as u64),
+                }))
+            }
+            crate::AttributeData::Boolean { values } => {
+                let values: Vec<u8> = self.read_array_of(values)?;
+                let distinct_count = (values.contains(&0) as u64) + (values.iter().any(|&v| v != 0) as u64);
+                Ok(Some(crate::attribute::AttributeSummary {
+                    count: values.len() as u64,
+                    null_count: 0,
+                    distinct_count: Some(distinct_count),
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Checks every number attribute on `project` for a null fraction of 99% or more, often a
+    /// sign that the attribute was attached at the wrong location (e.g. per-hole assay data
+    /// spread across per-vertex locations, leaving almost everything null), which is otherwise
+    /// invisible until someone opens the file and wonders why an attribute is empty.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn sparse_attribute_warnings(&mut self, project: &crate::Project) -> Result<Vec<Warning>> {
+        let mut warnings = Vec::new();
+        for element in &project.elements {
+            for attribute in &element.attributes {
+                if let Some(stats) = self.null_run_stats(attribute)? {
+                    if stats.is_mostly_null() {
+                        warnings.push(Warning {
+                            category: "mostly_null_attribute".to_string(),
+                            message: format!(
+                                "attribute \"{}\" on element \"{}\" is {:.1}% null ({} of {} \
+                                 values, longest run {}), check it's attached to the right \
+                                 location",
+                                attribute.name,
+                                element.name,
+                                stats.null_fraction() * 100.0,
+                                stats.null_count,
+                                stats.count,
+                                stats.longest_null_run
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// Runs [`crate::validate::check_manifold`] and a winding check (see
+    /// [`crate::geometry::check_winding`]) against every [`crate::geometry::Surface`] in
+    /// `project`, plus [`crate::validate::check_closed`] for one that declares
+    /// [`crate::geometry::Surface::closed`] (an open surface having boundary edges is expected,
+    /// not a problem, so that check only applies where closedness was actually promised). Skips a
+    /// [`crate::geometry::Surface::wide_indices`] surface (more than [`u32::MAX`] vertices), since
+    /// these checks key edges by `(u32, u32)` pairs.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn topology_warnings(&mut self, project: &crate::Project) -> Result<Vec<Warning>> {
+        let mut warnings = Vec::new();
+        for element in &project.elements {
+            let crate::geometry::Geometry::Surface(surface) = &element.geometry else { continue };
+            if surface.wide_indices {
+                continue;
+            }
+            let triangles: Vec<[u32; 3]> = self.read_array_u32x3(&surface.triangles)?;
+            let mut problems = crate::validate::check_manifold(&triangles);
+            let winding = crate::geometry::check_winding(&triangles);
+            if winding.flipped > 0 {
+                problems.push(crate::validate::TopologyProblem(format!(
+                    "{} of {} triangles disagree with the majority winding direction",
+                    winding.flipped,
+                    triangles.len()
+                )));
+            }
+            if surface.closed {
+                problems.extend(crate::validate::check_closed(&triangles));
+            }
+            for problem in problems {
+                warnings.push(Warning {
+                    category: "invalid_topology".to_string(),
+                    message: format!("surface \"{}\": {}", element.name, problem.0),
+                });
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// Reads a [`crate::AttributeData::Category`] attribute's index array and resolves each index
+    /// through `lookup` (its `names`, a [`crate::attribute::Colormap`]'s legend colors, or any
+    /// other per-category-index list) in one streaming pass (see
+    /// [`crate::attribute::resolve_category_indices`]). Returns `None` if `attribute` isn't a
+    /// category attribute.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn resolve_category<T: Clone>(
+        &mut self,
+        attribute: &crate::Attribute,
+        lookup: &[T],
+    ) -> Result<Option<Vec<Option<T>>>> {
+        let crate::AttributeData::Category { values, .. } = &attribute.data else {
+            return Ok(None);
+        };
+        let indices: Vec<u32> = self.read_array_of(values)?;
+        Ok(Some(crate::attribute::resolve_category_indices(&indices, lookup)))
+    }
+
+    /// Shorthand for [`Reader::resolve_category`] using the attribute's own `names`, the common
+    /// case of turning a category index array into the category name strings directly.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn resolve_category_names(&mut self, attribute: &crate::Attribute) -> Result<Option<Vec<Option<String>>>> {
+        let crate::AttributeData::Category { names, .. } = &attribute.data else {
+            return Ok(None);
+        };
+        let names = names.clone();
+        self.resolve_category(attribute, &names)
+    }
+
+    /// Reads `element`'s thumbnail PNG bytes, if [`crate::file::Writer::write_element_thumbnail`]
+    /// was used to attach one. Returns `None` if the element has no thumbnail recorded.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn element_thumbnail(&mut self, element: &crate::Element) -> Result<Option<Vec<u8>>> {
+        match crate::thumbnail::array_name(&element.metadata) {
+            Some(array_name) => Ok(Some(self.read_array_of(array_name)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads `project`'s thumbnail PNG bytes, the project-level equivalent of
+    /// [`Reader::element_thumbnail`].
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn project_thumbnail(&mut self, project: &crate::Project) -> Result<Option<Vec<u8>>> {
+        match crate::thumbnail::array_name(&project.metadata) {
+            Some(array_name) => Ok(Some(self.read_array_of(array_name)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// `element`'s thumbnail dimensions (see [`crate::thumbnail::png_dimensions`]), or `None` if
+    /// it has no thumbnail. This crate has no image codec dependency, so it can't resize or
+    /// decode pixel data on read; checking dimensions from the PNG header first is the
+    /// resource-conscious alternative available without one, e.g. before a viewer decides a
+    /// thumbnail is already small enough to display as-is.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn element_thumbnail_dimensions(&mut self, element: &crate::Element) -> Result<Option<(u32, u32)>> {
+        Ok(self.element_thumbnail(element)?.and_then(|bytes| crate::thumbnail::png_dimensions(&bytes)))
+    }
+
+    /// `project`'s thumbnail dimensions, the project-level equivalent of
+    /// [`Reader::element_thumbnail_dimensions`].
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn project_thumbnail_dimensions(&mut self, project: &crate::Project) -> Result<Option<(u32, u32)>> {
+        Ok(self.project_thumbnail(project)?.and_then(|bytes| crate::thumbnail::png_dimensions(&bytes)))
+    }
+
+    /// Reads a named array of any [`bytemuck::Pod`] element type. Used internally by the typed
+    /// `read_array_*` helpers above, and by [`crate::repair`] to inspect and rewrite attribute
+    /// value arrays generically. Requires the `parquet-read` feature (on via `parquet` by
+    /// default), since it decodes full array
+    /// contents rather than just structure.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub(crate) fn read_array_of<T: bytemuck::Pod>(&mut self, name: &str) -> Result<Vec<T>> {
+        self.read_array_of_with(name, &crate::cancel::CancellationToken::new(), |_, _| {})
+    }
+
+    /// Like the `read_array_*` methods, but checks `token` periodically and stops early with
+    /// [`crate::Error::Cancelled`] if it's been cancelled. Useful for very large arrays where the
+    /// caller wants to abort a read already in progress.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    fn read_array_of_cancelable<T: bytemuck::Pod>(
+        &mut self,
+        name: &str,
+        token: &crate::cancel::CancellationToken,
+    ) -> Result<Vec<T>> {
+        self.read_array_of_with(name, token, |_, _| {})
+    }
+
+    /// Like [`Reader::read_array_of_cancelable`], but also calls `on_progress(bytes_read,
+    /// total_bytes)` after each chunk, so callers can drive a progress bar while reading a large
+    /// array.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    fn read_array_of_with<T: bytemuck::Pod>(
+        &mut self,
+        name: &str,
+        token: &crate::cancel::CancellationToken,
+        mut on_progress: impl FnMut(u64, u64),
+    ) -> Result<Vec<T>> {
+        const CHUNK_SIZE: usize = 1 << 20;
+        let mut entry = self.archive.by_name(name)?;
+        let total = entry.size();
+        let mut bytes = Vec::with_capacity(total as usize);
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        loop {
+            token.check()?;
+            let read = entry.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            bytes.extend_from_slice(&chunk[..read]);
+            on_progress(bytes.len() as u64, total);
+        }
+        Ok(bytemuck::cast_slice(&bytes).to_vec())
+    }
+
+    /// Reads a named array of `[f64; 3]` values, reporting progress via `on_progress(bytes_read,
+    /// total_bytes)` as it goes.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_array_f64x3_with_progress(
+        &mut self,
+        name: &str,
+        on_progress: impl FnMut(u64, u64),
+    ) -> Result<Vec<[f64; 3]>> {
+        self.read_array_of_with(name, &crate::cancel::CancellationToken::new(), on_progress)
+    }
+
+    /// Opens a named array as a raw byte stream, without decoding it into any particular element
+    /// type. Used by [`crate::data::arrays_equal`] to compare arrays chunk by chunk instead of
+    /// materializing them fully.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub(crate) fn open_array(&mut self, name: &str) -> Result<zip::read::ZipFile<'_>> {
+        Ok(self.archive.by_name(name)?)
+    }
+
+    /// Reads a [`crate::AttributeData::Number`] attribute's values in batches of up to
+    /// `chunk_size`, instead of decoding the whole array into one `Vec<f64>` up front. For a block
+    /// model with tens or hundreds of millions of blocks, this bounds memory use to `chunk_size`
+    /// regardless of the array's total length, at the cost of per-batch call overhead instead of
+    /// per-value iterator overhead.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn array_numbers_chunks(&mut self, values_name: &str, chunk_size: usize) -> Result<NumberChunks<'_>> {
+        Ok(NumberChunks { entry: self.open_array(values_name)?, chunk_size, done: false })
+    }
+
+    /// Reads a block model attribute's values, always returning one entry per block in the whole
+    /// grid regardless of whether `model` uses [`crate::geometry::BlockModel::sparse`] storage:
+    /// for a sparse model, unoccupied blocks are filled with `default`. Exporters and other
+    /// consumers that want a simple dense view can use this instead of branching on
+    /// `model.sparse` themselves.
+    #[cfg(any(feature = "parquet", feature = "parquet-read"))]
+    pub fn read_block_values_dense<T: bytemuck::Pod>(
+        &mut self,
+        model: &crate::geometry::BlockModel,
+        values_name: &str,
+        default: T,
+    ) -> Result<Vec<T>> {
+        let values: Vec<T> = self.read_array_of(values_name)?;
+        match &model.sparse {
+            Some(sparse) => {
+                let indices: Vec<u64> = self.read_array_of(&sparse.indices)?;
+                Ok(crate::geometry::densify(model.block_count(), &indices, &values, default))
+            }
+            None => Ok(values),
+        }
+    }
+}
+
+/// Structural metadata about one array member of an `.omf` file, as returned by
+/// [`Reader::list_arrays`].
+#[derive(Debug, Clone)]
+pub struct ArrayInfo {
+    /// The array's name, as referenced from the project's geometry and attributes.
+    pub name: String,
+    /// The array's compressed size on disk, in bytes.
+    pub byte_size: u64,
+}
+
+/// Structural overview of a project returned by [`Reader::project_summary`].
+#[derive(Debug, Clone)]
+pub struct ProjectSummary {
+    /// The project's name.
+    pub name: String,
+    /// The OMF schema version the project claims to conform to, e.g. `"2.0"`.
+    pub version: String,
+    /// Keys present in the project's own top-level metadata map.
+    pub metadata_keys: Vec<String>,
+    /// One summary per element, in the same order as [`Project::elements`].
+    pub elements: Vec<ElementSummary>,
+}
+
+/// Structural overview of one element, as part of a [`ProjectSummary`].
+#[derive(Debug, Clone)]
+pub struct ElementSummary {
+    /// The element's name.
+    pub name: String,
+    /// The element's geometry type, e.g. `"PointSet"` or `"BlockModel"`, taken from the `"type"`
+    /// tag of its geometry object. Empty if the geometry is missing or malformed.
+    pub geometry_type: String,
+    /// Number of attributes attached to the element.
+    pub attribute_count: usize,
+    /// Keys present in the element's own metadata map.
+    pub metadata_keys: Vec<String>,
+}
+
+/// Builds an [`ElementSummary`] from one entry of `project.json`'s `"elements"` array, tolerating
+/// missing or malformed fields by falling back to empty defaults rather than failing the whole
+/// summary over one bad element.
+fn element_summary_of(element: &Value) -> ElementSummary {
+    let name = element.get("name").and_then(Value::as_str).unwrap_or_default().to_string();
+    let geometry_type = element
+        .get("geometry")
+        .and_then(|geometry| geometry.get("type"))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let attribute_count = element.get("attributes").and_then(Value::as_array).map_or(0, Vec::len);
+    let metadata_keys = object_keys(element.get("metadata"));
+    ElementSummary { name, geometry_type, attribute_count, metadata_keys }
+}
+
+/// Keys of `value` if it's a JSON object, or an empty list if it's absent or some other type.
+fn object_keys(value: Option<&Value>) -> Vec<String> {
+    value.and_then(Value::as_object).map(|map| map.keys().cloned().collect()).unwrap_or_default()
+}
+
+/// Iterator over a [`crate::AttributeData::Number`] attribute's values, yielded in batches of up
+/// to `chunk_size` by [`Reader::array_numbers_chunks`].
+#[cfg(any(feature = "parquet", feature = "parquet-read"))]
+pub struct NumberChunks<'a> {
+    entry: zip::read::ZipFile<'a>,
+    chunk_size: usize,
+    done: bool,
+}
+
+#[cfg(any(feature = "parquet", feature = "parquet-read"))]
+impl Iterator for NumberChunks<'_> {
+    type Item = Result<Vec<f64>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut buffer = vec![0u8; self.chunk_size * std::mem::size_of::<f64>()];
+        let mut filled = 0;
+        while filled < buffer.len() {
+            match self.entry.read(&mut buffer[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error.into()));
+                }
+            }
+        }
+        if filled == 0 {
+            self.done = true;
+            return None;
+        }
+        if filled < buffer.len() {
+            self.done = true;
+        }
+        if filled % std::mem::size_of::<f64>() != 0 {
+            return Some(Err(crate::Error::InvalidFile(
+                "number array length is not a whole number of 8-byte values".to_string(),
+            )));
+        }
+        buffer.truncate(filled);
+        Some(Ok(bytemuck::cast_slice(&buffer).to_vec()))
+    }
+}
+
+/// Decodes `bytes` as Latin-1 (ISO 8859-1), where every byte maps directly to the Unicode code
+/// point of the same value. Used as a fallback for `project.json` bodies that aren't valid UTF-8,
+/// per [`Reader::with_lenient_encoding`].
+fn decode_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| byte as char).collect()
+}
@@ -0,0 +1,39 @@
+//! Streaming re-compression of an existing `.omf` file at a different zip compression level.
+
+use std::io::{Read, Seek, Write};
+
+use zip::write::FileOptions;
+pub use zip::CompressionMethod;
+
+use crate::Result;
+
+use super::writer::is_large_file;
+
+/// Rewrites the archive read from `source` into `target`, storing every member (`project.json`
+/// and every array) with `method`/`level` instead of whatever compression the original file used.
+///
+/// Each member is copied by streaming its decompressed bytes straight into the new archive: no
+/// array is deserialized, quantized, or otherwise interpreted, so this is fast and safe to run on
+/// files this crate can't fully understand (e.g. a newer schema version with unfamiliar attribute
+/// kinds). Useful for quickly preparing a transfer-optimized (higher compression) or fast-read
+/// (`CompressionMethod::Stored`) variant of an existing file.
+pub fn recompress<R: Read + Seek, W: Write + Seek>(
+    source: R,
+    target: W,
+    method: CompressionMethod,
+    level: Option<i64>,
+) -> Result<W> {
+    let mut archive = zip::ZipArchive::new(source)?;
+    let mut zip = zip::ZipWriter::new(target);
+    for i in 0..archive.len() {
+        let name = archive.name_for_index(i).unwrap_or_default().to_string();
+        let mut entry = archive.by_index(i)?;
+        let options: FileOptions<()> = FileOptions::default()
+            .compression_method(method)
+            .compression_level(level)
+            .large_file(is_large_file(entry.size()));
+        zip.start_file(&name, options)?;
+        std::io::copy(&mut entry, &mut zip)?;
+    }
+    Ok(zip.finish()?)
+}
@@ -0,0 +1,35 @@
+//! Finds JSON object fields present in a raw `project.json` document that don't survive being
+//! parsed into [`crate::Project`] and re-serialized, i.e. fields this version of the crate doesn't
+//! recognize. Used by [`super::Reader::unrecognized_field_warnings`].
+//!
+//! This crate has no generated JSON schema to validate against; comparing the raw document to its
+//! own round trip serves the same purpose (catching a producer's typo'd or newer-version field
+//! name) without one. A genuine type mismatch on a field this crate *does* recognize already fails
+//! the initial parse outright, so there's nothing left for this comparison to catch there.
+
+use serde_json::Value;
+
+/// Appends a dotted path (in the style of [`crate::project::MetadataMatch::path`]) for every
+/// object key in `raw` that isn't present at the same position in `canonical`, recursing into
+/// matching objects and arrays.
+pub(super) fn find_unrecognized_fields(raw: &Value, canonical: &Value, path: &str, out: &mut Vec<String>) {
+    match (raw, canonical) {
+        (Value::Object(raw_fields), Value::Object(canonical_fields)) => {
+            for (key, raw_value) in raw_fields {
+                let child_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                match canonical_fields.get(key) {
+                    Some(canonical_value) => {
+                        find_unrecognized_fields(raw_value, canonical_value, &child_path, out)
+                    }
+                    None => out.push(child_path),
+                }
+            }
+        }
+        (Value::Array(raw_items), Value::Array(canonical_items)) => {
+            for (index, (raw_item, canonical_item)) in raw_items.iter().zip(canonical_items).enumerate() {
+                find_unrecognized_fields(raw_item, canonical_item, &format!("{path}[{index}]"), out);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,51 @@
+use std::io::{Read, Result as IoResult, Seek, SeekFrom};
+
+/// Presents a byte range `[offset, offset + len)` of an inner `Read + Seek` as if it were its
+/// own file starting at position zero. Lets [`crate::file::Reader`] operate on an OMF file that
+/// has been embedded inside a larger container (an encrypted archive, a tar file, a bundle
+/// format) without copying it out first, as long as the outer format exposes the byte range.
+pub struct SubFile<T> {
+    inner: T,
+    offset: u64,
+    len: u64,
+    position: u64,
+}
+
+impl<T: Read + Seek> SubFile<T> {
+    /// Wraps `inner`, exposing the `len` bytes starting at `offset`.
+    pub fn new(inner: T, offset: u64, len: u64) -> Self {
+        Self { inner, offset, len, position: 0 }
+    }
+}
+
+impl<T: Read + Seek> Read for SubFile<T> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let remaining = self.len.saturating_sub(self.position);
+        let max_read = remaining.min(buf.len() as u64) as usize;
+        if max_read == 0 {
+            return Ok(0);
+        }
+        self.inner.seek(SeekFrom::Start(self.offset + self.position))?;
+        let read = self.inner.read(&mut buf[..max_read])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<T: Read + Seek> Seek for SubFile<T> {
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        let new_position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.len as i64 + offset,
+            SeekFrom::Current(offset) => self.position as i64 + offset,
+        };
+        if new_position < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek before start of SubFile",
+            ));
+        }
+        self.position = new_position as u64;
+        Ok(self.position)
+    }
+}
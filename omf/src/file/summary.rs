@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// The attribute metadata key under which an [`ArraySummary`] is stored by
+/// [`crate::file::Writer::write_number_attribute`] and
+/// [`crate::file::Writer::write_number_attribute_constant`].
+pub const ARRAY_SUMMARY_METADATA_KEY: &str = "summary";
+
+/// Reads the [`ArraySummary`] recorded on `attribute`'s metadata, if any and well-formed, without
+/// decoding its values array.
+pub fn summary_of(attribute: &crate::Attribute) -> Option<ArraySummary> {
+    attribute.metadata.get(ARRAY_SUMMARY_METADATA_KEY).and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+/// Cheap summary statistics for one numeric array, computed once at write time and stored
+/// alongside it so readers that only need an overview (a legend range, a quick sanity check)
+/// don't have to decode the whole array.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ArraySummary {
+    /// Number of values, including nulls.
+    pub count: u64,
+    /// Number of null values.
+    pub null_count: u64,
+    /// Minimum non-null value, or `None` if there are none.
+    pub min: Option<f64>,
+    /// Maximum non-null value, or `None` if there are none.
+    pub max: Option<f64>,
+}
+
+impl ArraySummary {
+    /// Computes a summary over `values`, treating `NaN` as null.
+    pub fn compute(values: &[f64]) -> Self {
+        let mut min = None;
+        let mut max = None;
+        let mut null_count = 0;
+        for &value in values {
+            if value.is_nan() {
+                null_count += 1;
+                continue;
+            }
+            min = Some(min.map_or(value, |m: f64| m.min(value)));
+            max = Some(max.map_or(value, |m: f64| m.max(value)));
+        }
+        Self { count: values.len() as u64, null_count, min, max }
+    }
+
+    /// True if every non-null value is the same (including the trivial case of no values at
+    /// all), e.g. because the attribute was written with
+    /// [`crate::file::Writer::write_number_attribute_constant`]. Readers can use this to skip
+    /// decoding the array when only a single representative value is needed.
+    pub fn is_constant(&self) -> bool {
+        self.min == self.max
+    }
+}
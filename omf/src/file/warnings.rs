@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+/// A non-fatal problem noticed while reading a project, e.g. an attribute referencing a missing
+/// array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// A short category used to group and deduplicate similar warnings, e.g.
+    /// `"missing_array"`.
+    pub category: String,
+    /// The full warning message.
+    pub message: String,
+}
+
+/// One group of warnings sharing a category, produced by [`group_warnings`].
+#[derive(Debug, Clone)]
+pub struct WarningGroup {
+    /// The shared category.
+    pub category: String,
+    /// How many warnings (after deduplicating identical messages) fall in this category.
+    pub count: usize,
+    /// Up to a handful of example messages, so the group doesn't have to be printed in full to
+    /// be useful.
+    pub examples: Vec<String>,
+}
+
+/// Deduplicates identical warnings and groups the rest by category, so a file with (for example)
+/// a thousand identical "missing array" warnings reports as one group instead of drowning out
+/// everything else.
+pub fn group_warnings(warnings: &[Warning], examples_per_group: usize) -> Vec<WarningGroup> {
+    let mut groups: BTreeMap<String, (usize, Vec<String>)> = BTreeMap::new();
+    let mut seen = std::collections::HashSet::new();
+    for warning in warnings {
+        if !seen.insert((warning.category.as_str(), warning.message.as_str())) {
+            continue;
+        }
+        let entry = groups.entry(warning.category.clone()).or_default();
+        if entry.1.len() < examples_per_group {
+            entry.1.push(warning.message.clone());
+        }
+        entry.0 += 1;
+    }
+    groups
+        .into_iter()
+        .map(|(category, (count, examples))| WarningGroup { category, count, examples })
+        .collect()
+}
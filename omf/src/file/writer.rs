@@ -0,0 +1,794 @@
+use std::fs::File;
+use std::io::{Seek, Write as _};
+use std::time::{Duration, Instant};
+
+use zip::write::FileOptions;
+
+use crate::changelog::ChangelogEntry;
+use crate::compatibility::{downgrade, CompatibilityProfile, DowngradeReport};
+use crate::geometry::{normalize_winding, Geometry, Surface, WindingReport};
+use crate::problem::{ProblemCollector, Problems, Reason, Severity, ValidationOptions};
+use crate::quantization::Precision;
+use crate::{Attribute, AttributeData, BoundingBox, Crs, Element, Project, Result};
+
+use super::{ArraySummary, ArrayTiming, BenchmarkReport, PROJECT_JSON_NAME};
+
+/// Writes a [`Project`] and its associated arrays out to an `.omf` file.
+pub struct Writer<W> {
+    zip: zip::ZipWriter<W>,
+    /// Names already written or copied in this session, reported by [`Writer::has_array`].
+    written: std::collections::HashSet<String>,
+    /// Number of threads used to encode arrays in [`Writer::write_arrays_parallel`]. `1` means
+    /// no parallelism.
+    threads: usize,
+    /// How much to simplify the project for older/simpler readers before writing it. See
+    /// [`Writer::with_compatibility_profile`].
+    compatibility_profile: CompatibilityProfile,
+    /// Entries queued by [`Writer::with_changelog_entry`], appended to the project's changelog
+    /// in [`Writer::finish`].
+    changelog_entries: Vec<ChangelogEntry>,
+    /// If true, every array write records timing in `benchmark`. See
+    /// [`Writer::with_benchmarking`].
+    benchmarking: bool,
+    /// Per-array timings collected so far, returned from [`Writer::finish`].
+    benchmark: BenchmarkReport,
+    /// The exact format version [`Writer::finish`] must write, if pinned. See
+    /// [`Writer::with_pinned_version`].
+    pinned_version: Option<String>,
+    /// Default zip compression method and level for arrays written from this point on. See
+    /// [`Writer::with_compression`].
+    compression: zip::CompressionMethod,
+    compression_level: Option<i64>,
+    /// If true, [`Writer::finish`] fills in each element's [`crate::Element::bounding_box`] and
+    /// the project's own [`Project::bounding_box`] from the boxes recorded here. See
+    /// [`Writer::with_bounding_boxes`].
+    compute_bounding_boxes: bool,
+    /// Bounding boxes computed so far by the `write_surface*` helpers, keyed by the vertices
+    /// array name so [`Writer::finish`] can look one up for a given element's geometry.
+    bounding_boxes: std::collections::HashMap<String, BoundingBox>,
+    /// Applied by [`Writer::finish`] to the CRS, duplicate-id, and convention checks it runs.
+    /// See [`Writer::with_validation_options`].
+    validation_options: ValidationOptions,
+}
+
+impl Writer<File> {
+    /// Creates (or truncates) the `.omf` file at `path`.
+    pub fn create(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::new(File::create(path)?)
+    }
+
+    /// Opens an existing `.omf` file for editing: every array already in `path` is carried over
+    /// into `output_path` without being recompressed, so adding or removing a handful of
+    /// elements or attributes on a multi-gigabyte block model doesn't require rewriting its
+    /// untouched Parquet members. `output_path` must be different from `path`; to replace the
+    /// original file, write to a temporary path and rename it over `path` once `finish`
+    /// succeeds.
+    ///
+    /// The caller is responsible for building the edited [`Project`] (e.g. by reading the
+    /// existing one, mutating its `elements`, and dropping any array names that are no longer
+    /// referenced) and passing it to `finish`.
+    pub fn edit(path: impl AsRef<std::path::Path>, output_path: impl AsRef<std::path::Path>) -> Result<Self> {
+        if path.as_ref() == output_path.as_ref() {
+            return Err(crate::Error::InvalidFile(
+                "Writer::edit output_path must differ from the input path".to_string(),
+            ));
+        }
+        let source = File::open(path)?;
+        let mut archive = zip::ZipArchive::new(source)?;
+
+        let mut zip = zip::ZipWriter::new(File::create(output_path)?);
+        let mut written = std::collections::HashSet::new();
+        for i in 0..archive.len() {
+            let name = archive.name_for_index(i).unwrap_or_default().to_string();
+            if name == PROJECT_JSON_NAME {
+                // The project JSON is always rewritten by `finish`, never carried over as-is.
+                continue;
+            }
+            let entry = archive.by_index_raw(i)?;
+            zip.raw_copy_file(entry)?;
+            written.insert(name);
+        }
+        Ok(Self {
+            zip,
+            written,
+            threads: 1,
+            compatibility_profile: CompatibilityProfile::default(),
+            changelog_entries: Vec::new(),
+            benchmarking: false,
+            benchmark: BenchmarkReport::default(),
+            pinned_version: None,
+            compression: zip::CompressionMethod::Deflated,
+            compression_level: None,
+            compute_bounding_boxes: false,
+            bounding_boxes: std::collections::HashMap::new(),
+            validation_options: ValidationOptions::default(),
+        })
+    }
+}
+
+impl Writer<std::io::Cursor<Vec<u8>>> {
+    /// Builds the `.omf` file entirely in memory, for callers (e.g. a web service handler) that
+    /// want to hand the encoded bytes to a response body or another in-process consumer without
+    /// touching the filesystem. `finish` returns the completed bytes via
+    /// `std::io::Cursor::into_inner` on its returned cursor.
+    pub fn new_in_memory() -> Result<Self> {
+        Self::new(std::io::Cursor::new(Vec::new()))
+    }
+}
+
+impl<W: std::io::Write + Seek> Writer<W> {
+    /// Wraps a destination for a new `.omf` file.
+    pub fn new(target: W) -> Result<Self> {
+        Ok(Self {
+            zip: zip::ZipWriter::new(target),
+            written: std::collections::HashSet::new(),
+            threads: 1,
+            compatibility_profile: CompatibilityProfile::default(),
+            changelog_entries: Vec::new(),
+            benchmarking: false,
+            benchmark: BenchmarkReport::default(),
+            pinned_version: None,
+            compression: zip::CompressionMethod::Deflated,
+            compression_level: None,
+            compute_bounding_boxes: false,
+            bounding_boxes: std::collections::HashMap::new(),
+            validation_options: ValidationOptions::default(),
+        })
+    }
+
+    /// Sets the zip compression method and level used for every array written from this point on
+    /// by [`Writer::write_array`] and the `write_*_attribute*` helpers built on it. Defaults to
+    /// deflate at the zip crate's own default level, matching this crate's previous unconditional
+    /// behavior. `zip::CompressionMethod::Zstd` typically shrinks large block model arrays 30-40%
+    /// more than deflate at similar speed, at the cost of needing a reader built with Zstd
+    /// decompression support (any recent `zip` crate build has it; the compression method used is
+    /// recorded per member in the zip's own central directory, so [`super::Reader`] decodes each
+    /// array with whatever method it was actually written with, no separate configuration
+    /// needed). To use a different method for one specific array, call
+    /// [`Writer::write_array_with_compression`] instead of changing this default and back.
+    pub fn with_compression(mut self, method: zip::CompressionMethod, level: Option<i64>) -> Self {
+        self.compression = method;
+        self.compression_level = level;
+        self
+    }
+
+    /// Writes a single named array using `method`/`level` instead of the writer's default
+    /// compression (see [`Writer::with_compression`]), without changing that default for arrays
+    /// written afterward.
+    pub fn write_array_with_compression(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+        method: zip::CompressionMethod,
+        level: Option<i64>,
+    ) -> Result<()> {
+        let previous = (self.compression, self.compression_level);
+        self.compression = method;
+        self.compression_level = level;
+        let result = self.write_array(name, bytes);
+        (self.compression, self.compression_level) = previous;
+        result
+    }
+
+    /// If `enabled`, records how long each array spends encoding and being written, returned
+    /// from [`Writer::finish`] as a [`BenchmarkReport`]. Off by default, since timing every array
+    /// adds a small amount of overhead that isn't worth paying on a hot export path once the
+    /// bottleneck is known.
+    pub fn with_benchmarking(mut self, enabled: bool) -> Self {
+        self.benchmarking = enabled;
+        self
+    }
+
+    /// Requires [`Writer::finish`] to write exactly `version` as the project's
+    /// [`crate::Project::version`], refusing to write it otherwise, and rejects prerelease
+    /// versions outright (see [`crate::project::is_prerelease_version`]) even if they happen to
+    /// match. `None` (the default) writes [`crate::project::CURRENT_VERSION`] unconditionally.
+    ///
+    /// For regulated reporting chains that must guarantee every file they produce conforms to one
+    /// approved specification revision, catching a version drift after a crate upgrade instead of
+    /// silently shipping a file the downstream chain wasn't validated against.
+    pub fn with_pinned_version(mut self, version: Option<String>) -> Self {
+        self.pinned_version = version;
+        self
+    }
+
+    /// If `enabled`, [`Writer::write_surface`], [`Writer::write_solid`], and
+    /// [`Writer::write_surface_wide`] compute a [`BoundingBox`] over the vertices they're given
+    /// and [`Writer::finish`] records it on the corresponding [`crate::Element::bounding_box`],
+    /// plus their union on [`Project::bounding_box`]. Off by default: computing it costs a pass
+    /// over every vertex array, which callers who already know their project's extent (or don't
+    /// need one) shouldn't have to pay for.
+    ///
+    /// Only covers geometry written through those helpers; elements assembled by hand (e.g. a
+    /// [`crate::geometry::PointSet`] or [`crate::geometry::BlockModel`] built directly and passed
+    /// to [`Writer::finish`] with a caller-supplied `bounding_box`) are left as the caller set
+    /// them.
+    pub fn with_bounding_boxes(mut self, enabled: bool) -> Self {
+        self.compute_bounding_boxes = enabled;
+        self
+    }
+
+    /// Sets the [`ValidationOptions`] [`Writer::finish`] applies to the CRS
+    /// ([`crate::problem::Reason::InvalidCrs`]), duplicate-id
+    /// ([`crate::problem::Reason::DuplicateId`]), and composite convention
+    /// ([`crate::problem::Reason::ConventionViolation`]) checks it runs. All three default to
+    /// [`crate::problem::Severity::Error`], matching this crate's previous unconditional
+    /// behavior; override them to demote a check to a warning that's collected but doesn't stop
+    /// the write.
+    pub fn with_validation_options(mut self, options: ValidationOptions) -> Self {
+        self.validation_options = options;
+        self
+    }
+
+    /// Writes a single named array to the file, to be referenced from the project's geometry or
+    /// attributes by that same name. Must be called before [`Writer::finish`].
+    pub fn write_array(&mut self, name: &str, bytes: &[u8]) -> Result<()> {
+        self.write_array_timed(name, bytes, Duration::ZERO)
+    }
+
+    /// Writes a single named array, attributing `encode_duration` to it in the benchmark report
+    /// (see [`Writer::write_arrays_parallel`]) alongside the time spent here compressing and
+    /// writing it.
+    fn write_array_timed(&mut self, name: &str, bytes: &[u8], encode_duration: Duration) -> Result<()> {
+        let started = self.benchmarking.then(Instant::now);
+        let options: FileOptions<()> = FileOptions::default()
+            .compression_method(self.compression)
+            .compression_level(self.compression_level)
+            .large_file(is_large_file(bytes.len() as u64));
+        self.zip.start_file(name, options)?;
+        self.zip.write_all(bytes)?;
+        self.written.insert(name.to_string());
+        if let Some(started) = started {
+            self.benchmark.arrays.push(ArrayTiming {
+                name: name.to_string(),
+                encode: encode_duration,
+                write: started.elapsed(),
+            });
+        }
+        Ok(())
+    }
+
+    /// True if an array with this name is already present, whether carried over from
+    /// [`Writer::edit`] or written earlier in this session.
+    pub fn has_array(&self, name: &str) -> bool {
+        self.written.contains(name)
+    }
+
+    /// Sets the number of threads used to encode arrays passed to
+    /// [`Writer::write_arrays_parallel`]. Defaults to `1` (no parallelism). Encoding runs on a
+    /// short-lived rayon thread pool scoped to that call; appending the encoded bytes to the zip
+    /// container is always sequential, since the underlying archive isn't safe to write from
+    /// multiple threads at once.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Encodes and writes several arrays, using up to [`Writer::with_threads`] threads to do the
+    /// (CPU-bound) encoding in parallel before appending the results to the zip container one at
+    /// a time. Reduces wall-clock time writing multi-gigabyte block models with many attribute
+    /// arrays compared to encoding them one by one with [`Writer::write_array`].
+    pub fn write_arrays_parallel(
+        &mut self,
+        arrays: Vec<(String, Vec<u8>)>,
+        encode: impl Fn(&[u8]) -> Vec<u8> + Sync,
+    ) -> Result<()> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.threads)
+            .build()
+            .map_err(|e| crate::Error::InvalidFile(e.to_string()))?;
+        let encoded: Vec<(String, Vec<u8>, Duration)> = pool.install(|| {
+            use rayon::prelude::*;
+            arrays
+                .into_par_iter()
+                .map(|(name, bytes)| {
+                    let started = Instant::now();
+                    let encoded = encode(&bytes);
+                    (name, encoded, started.elapsed())
+                })
+                .collect()
+        });
+        for (name, bytes, encode_duration) in encoded {
+            self.write_array_timed(&name, &bytes, encode_duration)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a surface's vertex and triangle arrays under `name`, optionally normalizing
+    /// triangle winding first so consumers computing volumes get a consistent sign. Returns the
+    /// resulting [`Surface`] and a [`WindingReport`] of how many triangles were flipped (or, if
+    /// `fix_winding` is false, how many are inconsistent and were left as-is).
+    ///
+    /// Requires the `parquet-write` feature (on via `parquet` by default), since computing the
+    /// winding report inspects triangle contents rather than just writing caller-supplied bytes;
+    /// use [`Writer::write_array`] directly to write a surface's arrays without it.
+    #[cfg(any(feature = "parquet", feature = "parquet-write"))]
+    pub fn write_surface(
+        &mut self,
+        name: &str,
+        vertices: &[[f64; 3]],
+        mut triangles: Vec<[u32; 3]>,
+        fix_winding: bool,
+    ) -> Result<(Surface, WindingReport)> {
+        if crate::validate::requires_wide_indices(vertices.len()) {
+            return Err(crate::Error::Validation(format!(
+                "surface \"{name}\" has {} vertices, more than u32::MAX ({}); use \
+                 Writer::write_surface_wide instead",
+                vertices.len(),
+                u32::MAX
+            )));
+        }
+        let report = normalize_winding(&mut triangles, !fix_winding);
+        let vertices_name = format!("{name}-vertices");
+        let triangles_name = format!("{name}-triangles");
+        self.record_bounding_box(&vertices_name, vertices);
+        self.write_array(&vertices_name, bytemuck::cast_slice(vertices))?;
+        self.write_array(&triangles_name, bytemuck::cast_slice(&triangles))?;
+        let surface =
+            Surface { vertices: vertices_name, triangles: triangles_name, wide_indices: false, closed: false, origin: [0.0; 3] };
+        Ok((surface, report))
+    }
+
+    /// Writes a closed surface representing a solid volume (a stope, a pit design, an ore body),
+    /// setting [`Surface::closed`] so consumers can distinguish it from an open surface like a
+    /// topography programmatically, instead of re-deriving closure themselves. Validates closure
+    /// and winding consistency first with [`crate::validate::check_solid`] and returns
+    /// [`crate::Error::Validation`] listing the problems found rather than writing a surface that
+    /// silently isn't a valid solid.
+    ///
+    /// Requires the `parquet-write` feature (on via `parquet` by default), matching
+    /// [`Writer::write_surface`].
+    #[cfg(any(feature = "parquet", feature = "parquet-write"))]
+    pub fn write_solid(
+        &mut self,
+        name: &str,
+        vertices: &[[f64; 3]],
+        triangles: Vec<[u32; 3]>,
+    ) -> Result<Surface> {
+        let problems = crate::validate::check_solid(&triangles);
+        if !problems.is_empty() {
+            return Err(crate::Error::Validation(format!(
+                "surface \"{name}\" is not a valid solid: {}",
+                problems.iter().map(|p| p.0.clone()).collect::<Vec<_>>().join("; ")
+            )));
+        }
+        let (mut surface, _report) = self.write_surface(name, vertices, triangles, false)?;
+        surface.closed = true;
+        Ok(surface)
+    }
+
+    /// Writes a surface too large for `u32` triangle indices (more than
+    /// [`u32::MAX`] vertices), storing `triangles` as `[u64; 3]` (see
+    /// [`Surface::wide_indices`]). Unlike [`Writer::write_surface`], winding is not normalized:
+    /// [`normalize_winding`] only operates on `u32` indices, and a mesh at this scale is expected
+    /// to come from a merge step that already produced consistent winding.
+    ///
+    /// Requires the `parquet-write` feature (on via `parquet` by default), matching
+    /// [`Writer::write_surface`].
+    #[cfg(any(feature = "parquet", feature = "parquet-write"))]
+    pub fn write_surface_wide(
+        &mut self,
+        name: &str,
+        vertices: &[[f64; 3]],
+        triangles: &[[u64; 3]],
+    ) -> Result<Surface> {
+        let vertices_name = format!("{name}-vertices");
+        let triangles_name = format!("{name}-triangles");
+        self.record_bounding_box(&vertices_name, vertices);
+        self.write_array(&vertices_name, bytemuck::cast_slice(vertices))?;
+        self.write_array(&triangles_name, bytemuck::cast_slice(triangles))?;
+        Ok(Surface { vertices: vertices_name, triangles: triangles_name, wide_indices: true, closed: false, origin: [0.0; 3] })
+    }
+
+    /// Writes a numeric attribute's values under `name`, optionally rounding them first with
+    /// `precision` to improve Parquet compression. When `precision` is set, it's recorded in the
+    /// attribute's `metadata` under the key `"quantization"` so readers know the values have
+    /// already been rounded and to what.
+    ///
+    /// Requires the `parquet-write` feature (on via `parquet` by default), since it computes an
+    /// [`ArraySummary`] over the values; use [`Writer::write_array`] directly to write a number
+    /// attribute's values without it.
+    #[cfg(any(feature = "parquet", feature = "parquet-write"))]
+    pub fn write_number_attribute(
+        &mut self,
+        name: &str,
+        location: &str,
+        mut values: Vec<f64>,
+        precision: Option<Precision>,
+    ) -> Result<Attribute> {
+        let mut metadata = std::collections::BTreeMap::new();
+        if let Some(precision) = precision {
+            precision.apply_slice(&mut values);
+            let description = match precision {
+                Precision::Step(step) => serde_json::json!({"step": step}),
+                Precision::SignificantDigits(digits) => {
+                    serde_json::json!({"significant_digits": digits})
+                }
+            };
+            metadata.insert("quantization".to_string(), description);
+        }
+        metadata.insert(
+            super::ARRAY_SUMMARY_METADATA_KEY.to_string(),
+            serde_json::to_value(ArraySummary::compute(&values)).unwrap(),
+        );
+        let values_name = format!("{name}-values");
+        self.write_array(&values_name, bytemuck::cast_slice(&values))?;
+        Ok(Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            location: location.to_string(),
+            data: AttributeData::Number { values: values_name },
+            metadata,
+        })
+    }
+
+    /// Writes a Number attribute whose `count` values are all `value` (e.g. a uniform density of
+    /// `2.7` across hundreds of millions of blocks), streaming a small fixed-size buffer instead
+    /// of materializing the full array in memory like [`Writer::write_number_attribute`] would,
+    /// and computing its [`ArraySummary`] directly instead of scanning the values back out.
+    ///
+    /// Requires the `parquet-write` feature (on via `parquet` by default), matching
+    /// [`Writer::write_number_attribute`].
+    #[cfg(any(feature = "parquet", feature = "parquet-write"))]
+    pub fn write_number_attribute_constant(
+        &mut self,
+        name: &str,
+        location: &str,
+        value: f64,
+        count: usize,
+    ) -> Result<Attribute> {
+        let summary = ArraySummary {
+            count: count as u64,
+            null_count: if value.is_nan() { count as u64 } else { 0 },
+            min: (!value.is_nan()).then_some(value),
+            max: (!value.is_nan()).then_some(value),
+        };
+        let mut metadata = std::collections::BTreeMap::new();
+        metadata.insert(super::ARRAY_SUMMARY_METADATA_KEY.to_string(), serde_json::to_value(summary).unwrap());
+        let values_name = format!("{name}-values");
+        self.write_array_repeated(&values_name, value, count)?;
+        Ok(Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            location: location.to_string(),
+            data: AttributeData::Number { values: values_name },
+            metadata,
+        })
+    }
+
+    /// Writes a Boolean attribute whose `count` values are all `value`, using the same streaming
+    /// fast path as [`Writer::write_number_attribute_constant`].
+    pub fn write_boolean_attribute_constant(
+        &mut self,
+        name: &str,
+        location: &str,
+        value: bool,
+        count: usize,
+    ) -> Result<Attribute> {
+        let values_name = format!("{name}-values");
+        self.write_array_repeated(&values_name, value as u8, count)?;
+        Ok(Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            location: location.to_string(),
+            data: AttributeData::Boolean { values: values_name },
+            metadata: Default::default(),
+        })
+    }
+
+    /// Writes a Category attribute whose `count` values are all the same category `index`, using
+    /// the same streaming fast path as [`Writer::write_number_attribute_constant`]. Useful for a
+    /// domain code that's uniform across an entire element.
+    pub fn write_category_attribute_constant(
+        &mut self,
+        name: &str,
+        location: &str,
+        index: u32,
+        names: Vec<String>,
+        count: usize,
+    ) -> Result<Attribute> {
+        let values_name = format!("{name}-values");
+        self.write_array_repeated(&values_name, index, count)?;
+        Ok(Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            location: location.to_string(),
+            data: AttributeData::Category { values: values_name, names, descriptions: Vec::new() },
+            metadata: Default::default(),
+        })
+    }
+
+    /// Writes `count` copies of `value` under `name`, streaming a small fixed-size buffer instead
+    /// of materializing the whole array in memory first. The backing storage is still `count`
+    /// physical values (this format has no dedicated run-length-encoded array type), but Parquet's
+    /// own encoding compresses a constant column down to almost nothing, and this avoids ever
+    /// holding the uncompressed array in memory at all.
+    fn write_array_repeated<T: bytemuck::Pod>(&mut self, name: &str, value: T, count: usize) -> Result<()> {
+        const CHUNK_VALUES: usize = 8192;
+        let chunk = vec![value; CHUNK_VALUES.min(count.max(1))];
+        let chunk_bytes = bytemuck::cast_slice(&chunk);
+        let value_size = std::mem::size_of::<T>();
+        let options: FileOptions<()> = FileOptions::default()
+            .compression_method(self.compression)
+            .compression_level(self.compression_level)
+            .large_file(is_large_file((count * value_size) as u64));
+        self.zip.start_file(name, options)?;
+        let mut remaining = count;
+        while remaining > 0 {
+            let take = remaining.min(CHUNK_VALUES);
+            self.zip.write_all(&chunk_bytes[..take * value_size])?;
+            remaining -= take;
+        }
+        self.written.insert(name.to_string());
+        Ok(())
+    }
+
+    /// Writes `png_bytes` as `element`'s thumbnail and records the reference in
+    /// `element.metadata` (see [`crate::thumbnail`]), so file browsers and data portals can show
+    /// a preview without decoding the element's geometry. Validates that `png_bytes` starts with
+    /// the PNG signature and is within [`crate::Limits::max_thumbnail_bytes`] of the default
+    /// [`crate::Limits`].
+    pub fn write_element_thumbnail(&mut self, element: &mut crate::Element, png_bytes: &[u8]) -> Result<()> {
+        validate_thumbnail(png_bytes)?;
+        let array_name = format!("{}-thumbnail", element.name);
+        self.write_array(&array_name, png_bytes)?;
+        crate::thumbnail::set(&mut element.metadata, &array_name);
+        Ok(())
+    }
+
+    /// Writes `png_bytes` as `project`'s thumbnail, the project-level equivalent of
+    /// [`Writer::write_element_thumbnail`].
+    pub fn write_project_thumbnail(&mut self, project: &mut Project, png_bytes: &[u8]) -> Result<()> {
+        validate_thumbnail(png_bytes)?;
+        let array_name = "project-thumbnail".to_string();
+        self.write_array(&array_name, png_bytes)?;
+        crate::thumbnail::set(&mut project.metadata, &array_name);
+        Ok(())
+    }
+
+    /// Sets how much to simplify the project for older/simpler readers before writing it.
+    /// Defaults to [`CompatibilityProfile::Full`] (write everything as-is).
+    pub fn with_compatibility_profile(mut self, profile: CompatibilityProfile) -> Self {
+        self.compatibility_profile = profile;
+        self
+    }
+
+    /// Queues `entry` to be appended to the project's changelog (see [`crate::changelog`]) when
+    /// [`Writer::finish`] is called. Can be called more than once to record several entries.
+    pub fn with_changelog_entry(mut self, entry: ChangelogEntry) -> Self {
+        self.changelog_entries.push(entry);
+        self
+    }
+
+    /// Validates `project`, applies [`Writer::with_compatibility_profile`]'s downgrades if any,
+    /// and writes the result, finishing the zip container.
+    ///
+    /// The project JSON is written with canonicalized (sorted-key) metadata so that writing the
+    /// same logical project twice produces byte-for-byte identical output. Returns the finished
+    /// writer target, a [`DowngradeReport`] of anything the compatibility profile altered or
+    /// dropped (empty when writing with [`CompatibilityProfile::Full`]), a [`BenchmarkReport`] of
+    /// per-array timings (empty unless [`Writer::with_benchmarking`] was enabled), and every
+    /// non-fatal [`Problems`] found by the checks [`Writer::with_validation_options`] controls.
+    /// Fails outright instead if any of those problems' effective severity is
+    /// [`crate::problem::Severity::Error`] (the default for all three).
+    pub fn finish(mut self, project: &Project) -> Result<(W, DowngradeReport, BenchmarkReport, Problems)> {
+        let mut project = project.clone();
+        match &self.pinned_version {
+            Some(pinned) if crate::project::is_prerelease_version(pinned) => {
+                return Err(crate::Error::Validation(format!(
+                    "Writer::with_pinned_version was given prerelease version \"{pinned}\", which is refused outright"
+                )));
+            }
+            Some(pinned) if pinned != crate::project::CURRENT_VERSION => {
+                return Err(crate::Error::Validation(format!(
+                    "Writer::with_pinned_version requires \"{pinned}\", but this crate only writes \"{}\"",
+                    crate::project::CURRENT_VERSION
+                )));
+            }
+            _ => {}
+        }
+        assign_missing_ids(&mut project.elements);
+        let mut collector = ProblemCollector::new(self.validation_options.clone());
+        if let Some(problem) = project.coordinate_reference_system.as_ref().and_then(Crs::validate) {
+            collector.record(Reason::InvalidCrs, format!("project coordinate_reference_system is invalid: {problem}"));
+        }
+        collect_element_problems(&project.elements, &mut collector);
+        if collector.has_errors() {
+            let problems = collector.into_problems();
+            let messages: Vec<&str> =
+                problems.iter().filter(|p| p.severity == Severity::Error).map(|p| p.message.as_str()).collect();
+            return Err(crate::Error::Validation(messages.join("; ")));
+        }
+        let problems = collector.into_problems();
+        project.version = crate::project::CURRENT_VERSION.to_string();
+        for entry in self.changelog_entries.drain(..) {
+            crate::changelog::append(&mut project, entry);
+        }
+        if self.compute_bounding_boxes {
+            self.apply_bounding_boxes(&mut project);
+        }
+        let report = downgrade(&mut project, self.compatibility_profile);
+        let options: FileOptions<()> = FileOptions::default();
+        self.zip.start_file(PROJECT_JSON_NAME, options)?;
+        let mut value = serde_json::to_value(&project)?;
+        crate::metadata::canonicalize(&mut value);
+        let json = serde_json::to_vec_pretty(&value)?;
+        self.zip.write_all(&json)?;
+        Ok((self.zip.finish()?, report, self.benchmark, problems))
+    }
+
+    /// Records `vertices`' bounding box under `array_name`, if [`Writer::with_bounding_boxes`] is
+    /// enabled, for [`Writer::finish`] to pick up later.
+    fn record_bounding_box(&mut self, array_name: &str, vertices: &[[f64; 3]]) {
+        if !self.compute_bounding_boxes {
+            return;
+        }
+        if let Some(bounding_box) = BoundingBox::from_points(vertices) {
+            self.bounding_boxes.insert(array_name.to_string(), bounding_box);
+        }
+    }
+
+    /// Fills in each element's [`crate::Element::bounding_box`] from `self.bounding_boxes`, if
+    /// one was recorded for its geometry's vertices array and it doesn't already have one, then
+    /// sets `project.bounding_box` to the union of every element's box.
+    fn apply_bounding_boxes(&self, project: &mut Project) {
+        for element in &mut project.elements {
+            if element.bounding_box.is_none() {
+                if let Some(vertices_name) = vertices_array_name(&element.geometry) {
+                    element.bounding_box = self.bounding_boxes.get(vertices_name).copied();
+                }
+            }
+        }
+        project.bounding_box = project
+            .elements
+            .iter()
+            .filter_map(|element| element.bounding_box)
+            .reduce(|whole, part| whole.union(&part));
+    }
+}
+
+/// The name of the array holding a geometry's vertices, if it has one directly (as opposed to,
+/// e.g., a [`Geometry::Composite`], whose extent comes from its children instead).
+fn vertices_array_name(geometry: &Geometry) -> Option<&str> {
+    match geometry {
+        Geometry::PointSet(point_set) => Some(&point_set.vertices),
+        Geometry::LineSet(line_set) => Some(&line_set.vertices),
+        Geometry::Surface(surface) => Some(&surface.vertices),
+        Geometry::Composite(_) | Geometry::BlockModel(_) | Geometry::GridSurface(_) => None,
+    }
+}
+
+/// Fills in a fresh [`Element::id`]/[`Attribute::id`] wherever one is empty, recursing into
+/// composite children, so callers that build an [`Element`]/[`Attribute`] by hand without
+/// setting an id (rather than through a `Writer::write_*` helper, which always sets one) still
+/// get a stable identity once the project is written.
+fn assign_missing_ids(elements: &mut [Element]) {
+    for element in elements {
+        if element.id.is_empty() {
+            element.id = uuid::Uuid::new_v4().to_string();
+        }
+        for attribute in &mut element.attributes {
+            if attribute.id.is_empty() {
+                attribute.id = uuid::Uuid::new_v4().to_string();
+            }
+        }
+        if let Geometry::Composite(composite) = &mut element.geometry {
+            assign_missing_ids(&mut composite.elements);
+        }
+    }
+}
+
+/// Walks the element tree recording, into `collector`, every
+/// [`crate::problem::Reason::InvalidCrs`] (each element's own
+/// [`Element::coordinate_reference_system`]), [`crate::problem::Reason::DuplicateId`] (an
+/// [`Element::id`] or [`Attribute::id`] seen more than once, each in its own namespace), and
+/// [`crate::problem::Reason::ConventionViolation`] (a composite whose declared
+/// [`crate::convention`] rejects it) problem found, recursing into composite children.
+fn collect_element_problems(elements: &[Element], collector: &mut ProblemCollector) {
+    let mut element_ids = std::collections::HashSet::new();
+    let mut attribute_ids = std::collections::HashSet::new();
+    collect_element_problems_inner(elements, collector, &mut element_ids, &mut attribute_ids, "elements", None);
+}
+
+fn collect_element_problems_inner(
+    elements: &[Element],
+    collector: &mut ProblemCollector,
+    element_ids: &mut std::collections::HashSet<String>,
+    attribute_ids: &mut std::collections::HashSet<String>,
+    path_prefix: &str,
+    top_level_index: Option<usize>,
+) {
+    for (index, element) in elements.iter().enumerate() {
+        let path = format!("{path_prefix}[{index}]");
+        let element_index = top_level_index.or(Some(index));
+        if let Some(problem) = element.coordinate_reference_system.as_ref().and_then(Crs::validate) {
+            collector.record_at(
+                Reason::InvalidCrs,
+                format!("element \"{}\" coordinate_reference_system is invalid: {problem}", element.name),
+                element_index,
+                Some(format!("{path}.coordinate_reference_system")),
+            );
+        }
+        if !element_ids.insert(element.id.clone()) {
+            collector.record_at(
+                Reason::DuplicateId,
+                format!("duplicate element id \"{}\" (element \"{}\")", element.id, element.name),
+                element_index,
+                Some(format!("{path}.id")),
+            );
+        }
+        for (attribute_index, attribute) in element.attributes.iter().enumerate() {
+            if !attribute_ids.insert(attribute.id.clone()) {
+                collector.record_at(
+                    Reason::DuplicateId,
+                    format!(
+                        "duplicate attribute id \"{}\" (attribute \"{}\" on element \"{}\")",
+                        attribute.id, attribute.name, element.name
+                    ),
+                    element_index,
+                    Some(format!("{path}.attributes[{attribute_index}].id")),
+                );
+            }
+        }
+        if let Geometry::Composite(composite) = &element.geometry {
+            if let Err(problem) = crate::convention::validate_against_convention(&element.metadata, composite) {
+                collector.record_at(
+                    Reason::ConventionViolation,
+                    format!("composite \"{}\": {problem}", element.name),
+                    element_index,
+                    Some(path.clone()),
+                );
+            }
+            collect_element_problems_inner(
+                &composite.elements,
+                collector,
+                element_ids,
+                attribute_ids,
+                &format!("{path}.elements"),
+                element_index,
+            );
+        }
+    }
+}
+
+/// True if `size` needs a Zip64 local file header (the classic zip format's fields overflow past
+/// 4 GiB). Passed to `zip::write::FileOptions::large_file` up front, since every array write here
+/// knows its final size before calling `start_file`; without it, the `zip` crate only learns the
+/// size is too big after already committing to a 32-bit header, which would corrupt any block
+/// model array over 4 GiB.
+pub(super) fn is_large_file(size: u64) -> bool {
+    size > u32::MAX as u64
+}
+
+#[cfg(test)]
+mod is_large_file_tests {
+    use super::is_large_file;
+
+    #[test]
+    fn sizes_at_and_below_the_u32_boundary_are_not_large() {
+        assert!(!is_large_file(0));
+        assert!(!is_large_file(u32::MAX as u64));
+    }
+
+    #[test]
+    fn sizes_above_the_u32_boundary_are_large() {
+        assert!(is_large_file(u32::MAX as u64 + 1));
+        assert!(is_large_file(u64::MAX));
+    }
+}
+
+/// Checks `png_bytes` is a PNG within the default [`crate::Limits::max_thumbnail_bytes`], shared
+/// by [`Writer::write_element_thumbnail`] and [`Writer::write_project_thumbnail`].
+fn validate_thumbnail(png_bytes: &[u8]) -> Result<()> {
+    if !crate::thumbnail::is_png(png_bytes) {
+        return Err(crate::Error::Validation("thumbnail is not a valid PNG (missing PNG signature)".to_string()));
+    }
+    let limit = crate::Limits::default().max_thumbnail_bytes;
+    if png_bytes.len() as u64 > limit {
+        return Err(crate::Error::Validation(format!(
+            "thumbnail is {} bytes, exceeds the {limit} byte limit",
+            png_bytes.len()
+        )));
+    }
+    Ok(())
+}
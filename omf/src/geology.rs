@@ -0,0 +1,117 @@
+//! Convention for geological models: a [`crate::geometry::Composite`] made of closed surfaces
+//! (stope shells, lithology domains, ore body wireframes, ...) that all share the same origin, so
+//! their vertex arrays are directly comparable without each reader having to re-derive a common
+//! reference frame.
+//!
+//! Registered with [`crate::convention`] under [`GEOLOGICAL_MODEL_CONVENTION_NAME`] once
+//! [`register`] has been called; like every convention there, this is enforced by agreement
+//! between writers and readers, not by the OMF format itself.
+//!
+//! There's no bespoke C or Python accessor for this convention: the `convention` metadata key it
+//! reads and writes is reachable from C through
+//! `omf_composite_builder_set_element_field_string`/`..._get_element_field_string`, and from
+//! Python through `omf.Reader.query_metadata`, the same generic paths every other
+//! metadata-convention field (see [`crate::drillhole`], [`crate::attribute::NumberDisplayHint`])
+//! already goes through.
+
+use crate::convention::{Convention, CONVENTION_METADATA_KEY};
+use crate::geometry::{Composite, Geometry};
+use crate::project::Element;
+
+/// The convention's name, matched against a composite's `metadata[CONVENTION_METADATA_KEY]`.
+pub const GEOLOGICAL_MODEL_CONVENTION_NAME: &str = "geological_model";
+
+/// Builds a geological model composite [`Element`] from its already-built domain surfaces, each
+/// of which must have [`crate::geometry::Geometry::Surface`] geometry with
+/// [`crate::geometry::Surface::closed`] set. Sets the composite's own
+/// `metadata[CONVENTION_METADATA_KEY]` so [`GeologicalModelConvention::validate`] (and any other
+/// convention-aware reader) recognizes it.
+///
+/// This function only tags and assembles the composite; it doesn't close or reorigin the
+/// surfaces themselves, so callers should build each domain with
+/// [`crate::file::Writer::write_solid`] first.
+pub fn new_geological_model(name: impl Into<String>, domains: Vec<Element>) -> Element {
+    let mut metadata = std::collections::BTreeMap::new();
+    metadata.insert(CONVENTION_METADATA_KEY.to_string(), serde_json::json!(GEOLOGICAL_MODEL_CONVENTION_NAME));
+    Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.into(),
+        description: String::new(),
+        geometry: Geometry::Composite(Composite { elements: domains }),
+        attributes: Vec::new(),
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata,
+    }
+}
+
+/// True if `element`'s metadata declares it a geological model composite, without running the
+/// full [`GeologicalModelConvention::validate`] check. Useful for importers that want to route
+/// recognized composites to specialized handling before deciding whether to validate them.
+pub fn is_geological_model(element: &Element) -> bool {
+    element.metadata.get(CONVENTION_METADATA_KEY).and_then(|v| v.as_str())
+        == Some(GEOLOGICAL_MODEL_CONVENTION_NAME)
+}
+
+/// The registered [`Convention`] for geological model composites. Register it with
+/// [`crate::convention::register_convention`] (see [`register`]) so
+/// [`crate::convention::validate_against_convention`] picks it up.
+pub struct GeologicalModelConvention;
+
+impl Convention for GeologicalModelConvention {
+    fn name(&self) -> &str {
+        GEOLOGICAL_MODEL_CONVENTION_NAME
+    }
+
+    fn validate(&self, composite: &Composite) -> Option<String> {
+        if composite.elements.is_empty() {
+            return Some("geological model composite must have at least one domain surface".to_string());
+        }
+        let mut shared_origin: Option<[f64; 3]> = None;
+        for element in &composite.elements {
+            let Geometry::Surface(surface) = &element.geometry else {
+                return Some(format!(
+                    "geological model domain \"{}\" must have Surface geometry, not {}",
+                    element.name,
+                    geometry_type_name(&element.geometry)
+                ));
+            };
+            if !surface.closed {
+                return Some(format!(
+                    "geological model domain \"{}\" must be a closed surface",
+                    element.name
+                ));
+            }
+            match shared_origin {
+                None => shared_origin = Some(surface.origin),
+                Some(origin) if origin != surface.origin => {
+                    return Some(format!(
+                        "geological model domain \"{}\" has origin {:?}, expected all domains to \
+                         share the same origin {:?}",
+                        element.name, surface.origin, origin
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        None
+    }
+}
+
+fn geometry_type_name(geometry: &Geometry) -> &'static str {
+    match geometry {
+        Geometry::PointSet(_) => "PointSet",
+        Geometry::LineSet(_) => "LineSet",
+        Geometry::Surface(_) => "Surface",
+        Geometry::Composite(_) => "Composite",
+        Geometry::BlockModel(_) => "BlockModel",
+        Geometry::GridSurface(_) => "GridSurface",
+    }
+}
+
+/// Registers [`GeologicalModelConvention`] with [`crate::convention::register_convention`]. Not
+/// called automatically: like every convention, opting in is a deliberate choice for embedders
+/// that want [`crate::convention::validate_against_convention`] to enforce this shape.
+pub fn register() {
+    crate::convention::register_convention(Box::new(GeologicalModelConvention));
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use super::sparse::SparseBlocks;
+use super::BlockIndex;
+
+/// A regular block model: a 3D grid of equally sized blocks with an arbitrary orientation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockModel {
+    /// Number of blocks along each axis.
+    pub count: [u32; 3],
+    /// Size of each block along each axis, in the grid's own axes.
+    pub size: [f64; 3],
+    /// World-space origin of block `[0, 0, 0]`.
+    pub origin: [f64; 3],
+    /// The three grid axis directions, not necessarily orthogonal to the world axes.
+    pub axes: [[f64; 3]; 3],
+    /// If set, only the listed blocks exist and attribute arrays have one row per occupied block
+    /// instead of one row per block in the whole grid. See [`super::sparse`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse: Option<SparseBlocks>,
+}
+
+impl BlockModel {
+    /// Total number of blocks in the model.
+    pub fn block_count(&self) -> u64 {
+        self.count.iter().map(|&c| c as u64).product()
+    }
+
+    /// Volume of a single block, in the same units as `size`, cubed.
+    pub fn block_volume(&self) -> f64 {
+        self.size[0] * self.size[1] * self.size[2]
+    }
+
+    /// The world-space position of block `index`'s minimum corner, the corner nearest `origin`
+    /// along each of `axes`, or `None` if `index` is out of range for `count`.
+    pub fn block_corner(&self, index: BlockIndex) -> Option<[f64; 3]> {
+        let [i, j, k] = index.0;
+        let [ni, nj, nk] = self.count;
+        if i >= ni || j >= nj || k >= nk {
+            return None;
+        }
+        Some(self.grid_to_world([i as f64, j as f64, k as f64]))
+    }
+
+    /// The world-space centroid of block `index`, or `None` if out of range.
+    pub fn block_centroid(&self, index: BlockIndex) -> Option<[f64; 3]> {
+        let [i, j, k] = index.0;
+        let [ni, nj, nk] = self.count;
+        if i >= ni || j >= nj || k >= nk {
+            return None;
+        }
+        Some(self.grid_to_world([i as f64 + 0.5, j as f64 + 0.5, k as f64 + 0.5]))
+    }
+
+    /// The world-space centroid of the block at linear index `linear` (see
+    /// [`BlockIndex::to_linear`]), or `None` if out of range. Convenience for walking a
+    /// [`SparseBlocks::indices`] array, whose entries are already in this linearized form,
+    /// without decoding each one back to a [`BlockIndex`] by hand first.
+    pub fn centroid_at_linear(&self, linear: u64) -> Option<[f64; 3]> {
+        self.block_centroid(BlockIndex::from_linear(linear, self)?)
+    }
+
+    /// Iterates the world-space centroid of every block in the grid, in the same linear order as
+    /// [`BlockIndex::to_linear`] (so it lines up one-to-one with a dense, non-sparse attribute
+    /// array). For a sparse model (see [`BlockModel::sparse`]), map `SparseBlocks::indices`'
+    /// decoded values through [`BlockModel::centroid_at_linear`] instead, to skip unoccupied
+    /// blocks rather than computing a centroid for every block in the (mostly empty) whole grid.
+    pub fn centroids(&self) -> impl Iterator<Item = [f64; 3]> + '_ {
+        let [ni, nj, nk] = self.count;
+        (0..ni).flat_map(move |i| {
+            (0..nj).flat_map(move |j| {
+                (0..nk).map(move |k| self.grid_to_world([i as f64 + 0.5, j as f64 + 0.5, k as f64 + 0.5]))
+            })
+        })
+    }
+
+    /// Converts fractional grid coordinates (integers for a block corner, `+ 0.5` on each axis
+    /// for a centroid) into world coordinates, honoring `origin`, `size`, and `axes`. This crate
+    /// has no separate tensor-grid or explicit sub-block geometry variant; a sub-blocked model's
+    /// finer positions (see [`super::SubblockIndex::to_global`]) are still points on this same
+    /// regular grid, just addressed at sub-block rather than block resolution, so this one
+    /// conversion covers both.
+    pub(super) fn grid_to_world(&self, grid: [f64; 3]) -> [f64; 3] {
+        let mut world = self.origin;
+        for axis in 0..3 {
+            let offset = grid[axis] * self.size[axis];
+            for i in 0..3 {
+                world[i] += self.axes[axis][i] * offset;
+            }
+        }
+        world
+    }
+}
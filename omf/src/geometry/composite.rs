@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::project::Element;
+
+/// A composite geometry made up of nested child elements, e.g. a drillhole made of several
+/// line-set intervals, or a multi-part CAD assembly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Composite {
+    /// The nested elements. Each has its own geometry and attributes, and may itself be a
+    /// composite.
+    pub elements: Vec<Element>,
+}
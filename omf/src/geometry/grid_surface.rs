@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use super::TensorAxis;
+
+/// Spacing of a [`GridSurface`] along one axis: either uniform cells, or a [`TensorAxis`] of
+/// varying cell sizes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum GridSpacing {
+    /// `count` cells, each `size` units wide.
+    Regular { count: u32, size: f64 },
+    /// Cells with individually specified widths.
+    Tensor { sizes: Vec<f64> },
+}
+
+impl GridSpacing {
+    /// Number of cells along this axis.
+    pub fn count(&self) -> u32 {
+        match self {
+            GridSpacing::Regular { count, .. } => *count,
+            GridSpacing::Tensor { sizes } => sizes.len() as u32,
+        }
+    }
+
+    /// Cumulative offset of each vertex along this axis from the grid's origin: one more entry
+    /// than [`GridSpacing::count`], starting at zero, in the same style as
+    /// [`super::TensorAxis::to_boundaries`].
+    fn vertex_offsets(&self) -> Vec<f64> {
+        match self {
+            GridSpacing::Regular { count, size } => (0..=*count).map(|i| i as f64 * size).collect(),
+            GridSpacing::Tensor { sizes } => TensorAxis(sizes.clone()).to_boundaries(),
+        }
+    }
+}
+
+/// A 2D grid surface embedded in 3D space via an origin and two axis directions: a heightmap-like
+/// surface (regular or tensor spacing) draped over a plane, or a flat 2D plan map when
+/// `vertical_offsets` is absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridSurface {
+    /// World-space origin of grid cell `[0, 0]`.
+    pub origin: [f64; 3],
+    /// Direction of the grid's first (u) axis.
+    pub axis_u: [f64; 3],
+    /// Direction of the grid's second (v) axis.
+    pub axis_v: [f64; 3],
+    /// Spacing along the u axis.
+    pub spacing_u: GridSpacing,
+    /// Spacing along the v axis.
+    pub spacing_v: GridSpacing,
+    /// Optional per-vertex height offset along the plane's normal. `None` means a flat plan map:
+    /// a first-class 2D surface with no draping, useful for plan-view maps and cross sections
+    /// that don't need a third dimension.
+    #[serde(default)]
+    pub vertical_offsets: Option<String>,
+}
+
+impl GridSurface {
+    /// True if this grid has no vertical offsets, i.e. it's a flat 2D plan map.
+    pub fn is_flat(&self) -> bool {
+        self.vertical_offsets.is_none()
+    }
+
+    /// Builds a flat, axis-aligned plan map on the XY plane with regular spacing — the common
+    /// case for a 2D-only project with no elevation data.
+    pub fn plan_map(origin: [f64; 2], count: [u32; 2], size: [f64; 2]) -> Self {
+        Self {
+            origin: [origin[0], origin[1], 0.0],
+            axis_u: [1.0, 0.0, 0.0],
+            axis_v: [0.0, 1.0, 0.0],
+            spacing_u: GridSpacing::Regular { count: count[0], size: size[0] },
+            spacing_v: GridSpacing::Regular { count: count[1], size: size[1] },
+            vertical_offsets: None,
+        }
+    }
+
+    /// Number of vertices along the u and v axes: one more than the cell count along each, since
+    /// a grid of `nu` by `nv` cells has `(nu + 1)` by `(nv + 1)` vertices.
+    pub fn vertex_counts(&self) -> [u32; 2] {
+        [self.spacing_u.count() + 1, self.spacing_v.count() + 1]
+    }
+
+    /// Total number of vertices, i.e. the length `heights` must have when passed to
+    /// [`GridSurface::vertex_positions`].
+    pub fn vertex_count(&self) -> u64 {
+        let [nu, nv] = self.vertex_counts();
+        nu as u64 * nv as u64
+    }
+
+    /// Every vertex's world-space position, in row-major order with `v` fastest (`u` outer, `v`
+    /// inner, matching how [`super::slice_block_model`] lays out its own grid values), draping
+    /// `heights` along the plane's normal (`axis_u` × `axis_v`) if given. `heights` must have one
+    /// entry per vertex (see [`GridSurface::vertex_count`]); pass `None` for a flat plan map
+    /// ([`GridSurface::is_flat`]), which has no [`GridSurface::vertical_offsets`] array to decode
+    /// in the first place.
+    ///
+    /// # Panics
+    /// Panics if `heights` is `Some` and shorter than [`GridSurface::vertex_count`].
+    pub fn vertex_positions(&self, heights: Option<&[f64]>) -> Vec<[f64; 3]> {
+        let u_offsets = self.spacing_u.vertex_offsets();
+        let v_offsets = self.spacing_v.vertex_offsets();
+        let normal = heights.map(|_| normalize(cross(self.axis_u, self.axis_v)));
+        let mut positions = Vec::with_capacity(u_offsets.len() * v_offsets.len());
+        for (ui, &u) in u_offsets.iter().enumerate() {
+            for (vi, &v) in v_offsets.iter().enumerate() {
+                let mut position = self.origin;
+                for i in 0..3 {
+                    position[i] += self.axis_u[i] * u + self.axis_v[i] * v;
+                }
+                if let (Some(heights), Some(normal)) = (heights, normal) {
+                    let height = heights[ui * v_offsets.len() + vi];
+                    for i in 0..3 {
+                        position[i] += normal[i] * height;
+                    }
+                }
+                positions.push(position);
+            }
+        }
+        positions
+    }
+}
+
+/// The cross product of two 3D vectors.
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Scales a 3D vector to unit length, or leaves it as-is if it's (near) zero length, e.g. because
+/// `axis_u` and `axis_v` are degenerate (parallel or zero), so a caller draping heights onto a
+/// malformed grid gets an inert offset rather than `NaN` positions.
+fn normalize(v: [f64; 3]) -> [f64; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length < f64::EPSILON {
+        v
+    } else {
+        [v[0] / length, v[1] / length, v[2] / length]
+    }
+}
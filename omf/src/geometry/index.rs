@@ -0,0 +1,107 @@
+//! Strictly-typed indices into a [`super::BlockModel`], to stop parent-block and sub-block
+//! indices (and their linearized forms) from being accidentally mixed up.
+
+use super::BlockModel;
+
+/// The `[i, j, k]` grid coordinates of one parent block in a [`BlockModel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockIndex(pub [u32; 3]);
+
+/// The `[i, j, k]` grid coordinates of one sub-block within its parent block's local grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubblockIndex(pub [u32; 3]);
+
+impl BlockIndex {
+    /// Converts to a linear index in row-major (`i` slowest, `k` fastest) order over `model`'s
+    /// block count, or `None` if out of range.
+    pub fn to_linear(self, model: &BlockModel) -> Option<u64> {
+        let [i, j, k] = self.0;
+        let [ni, nj, nk] = model.count;
+        if i >= ni || j >= nj || k >= nk {
+            return None;
+        }
+        Some((i as u64 * nj as u64 + j as u64) * nk as u64 + k as u64)
+    }
+
+    /// The inverse of [`BlockIndex::to_linear`].
+    pub fn from_linear(linear: u64, model: &BlockModel) -> Option<Self> {
+        let [_, nj, nk] = model.count;
+        if linear >= model.block_count() {
+            return None;
+        }
+        let k = (linear % nk as u64) as u32;
+        let j = ((linear / nk as u64) % nj as u64) as u32;
+        let i = (linear / (nk as u64 * nj as u64)) as u32;
+        Some(BlockIndex([i, j, k]))
+    }
+}
+
+impl SubblockIndex {
+    /// Combines a parent [`BlockIndex`] and this sub-block index into world-relative grid
+    /// coordinates, given the number of sub-blocks per parent along each axis.
+    pub fn to_global(self, parent: BlockIndex, subblocks_per_parent: [u32; 3]) -> [u32; 3] {
+        let mut global = [0u32; 3];
+        for axis in 0..3 {
+            global[axis] = parent.0[axis] * subblocks_per_parent[axis] + self.0[axis];
+        }
+        global
+    }
+
+    /// The world-space centroid of this sub-block, given its parent block's index, the number of
+    /// sub-blocks per parent along each axis (each parent block divided evenly into
+    /// `subblocks_per_parent[axis]` equal parts along that axis), and the owning `model`. Returns
+    /// `None` if `parent` is out of range for `model` or `self` is out of range for
+    /// `subblocks_per_parent`.
+    pub fn subblock_centroid(
+        self,
+        parent: BlockIndex,
+        subblocks_per_parent: [u32; 3],
+        model: &BlockModel,
+    ) -> Option<[f64; 3]> {
+        let [pi, pj, pk] = parent.0;
+        let [ni, nj, nk] = model.count;
+        if pi >= ni || pj >= nj || pk >= nk {
+            return None;
+        }
+        let [si, sj, sk] = self.0;
+        let [spi, spj, spk] = subblocks_per_parent;
+        if si >= spi || sj >= spj || sk >= spk {
+            return None;
+        }
+        Some(model.grid_to_world([
+            pi as f64 + (si as f64 + 0.5) / spi as f64,
+            pj as f64 + (sj as f64 + 0.5) / spj as f64,
+            pk as f64 + (sk as f64 + 0.5) / spk as f64,
+        ]))
+    }
+}
+
+/// Validates that every value in `parent_linear_indices` is a valid linear parent-block index
+/// (see [`BlockIndex::to_linear`]) for `model`.
+///
+/// Attributes located at `"subblocks"` are ordered by the model's sub-block array, one row per
+/// sub-block, each row carrying its own reference back to the parent block it belongs to
+/// (typically `parent_linear_indices` is itself that reference array's decoded values); attributes
+/// located at `"parent_blocks"` are ordered by [`BlockIndex::to_linear`] over the whole grid. This
+/// is the mismatch most consumer bugs come from, so it's worth checking explicitly rather than
+/// discovering an out-of-range parent reference partway through some other computation.
+pub fn validate_subblock_parents(parent_linear_indices: &[u64], model: &BlockModel) -> Result<(), String> {
+    let block_count = model.block_count();
+    if let Some(&bad) = parent_linear_indices.iter().find(|&i| *i >= block_count) {
+        return Err(format!(
+            "sub-block parent index {bad} is out of range for a model with {block_count} blocks"
+        ));
+    }
+    Ok(())
+}
+
+/// Iterates `(parent_linear_index, value)` pairs for a `"subblocks"`-located attribute's values,
+/// zipped against the sub-block array's own per-row parent references (see
+/// [`validate_subblock_parents`]), so callers converting sub-block values into parent-block order
+/// don't have to align the two arrays by hand.
+pub fn zip_parent_linear<'v, T>(
+    parent_linear_indices: &'v [u64],
+    values: &'v [T],
+) -> impl Iterator<Item = (u64, &'v T)> {
+    parent_linear_indices.iter().copied().zip(values.iter())
+}
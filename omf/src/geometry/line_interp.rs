@@ -0,0 +1,34 @@
+/// Linearly interpolates a per-vertex attribute value at an arbitrary point along a line-set
+/// segment, e.g. for sampling a drillhole's assay values at a depth that falls between two
+/// stored vertices.
+///
+/// `t` is the fraction of the way from `segment[0]` to `segment[1]`, in `[0, 1]`; values outside
+/// that range are clamped.
+pub fn interpolate_segment_value(values: &[f64], segment: [u32; 2], t: f64) -> f64 {
+    let t = t.clamp(0.0, 1.0);
+    let a = values[segment[0] as usize];
+    let b = values[segment[1] as usize];
+    a + (b - a) * t
+}
+
+/// Finds the fractional position `t` of `point` along `segment`'s vertices by projecting it onto
+/// the segment, then interpolates `values` there via [`interpolate_segment_value`]. Returns
+/// `None` if the segment's two vertices coincide.
+pub fn interpolate_at_point(
+    vertices: &[[f64; 3]],
+    values: &[f64],
+    segment: [u32; 2],
+    point: [f64; 3],
+) -> Option<f64> {
+    let a = vertices[segment[0] as usize];
+    let b = vertices[segment[1] as usize];
+    let ab: [f64; 3] = std::array::from_fn(|i| b[i] - a[i]);
+    let ap: [f64; 3] = std::array::from_fn(|i| point[i] - a[i]);
+    let ab_len_sq: f64 = ab.iter().map(|x| x * x).sum();
+    if ab_len_sq == 0.0 {
+        return None;
+    }
+    let dot: f64 = (0..3).map(|i| ab[i] * ap[i]).sum();
+    let t = dot / ab_len_sq;
+    Some(interpolate_segment_value(values, segment, t))
+}
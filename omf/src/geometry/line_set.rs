@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// A set of line segments, each defined by a pair of vertex indices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineSet {
+    /// Reference to the array of `[f64; 3]` vertex positions in the file's data section.
+    pub vertices: String,
+    /// Reference to the array of vertex index pairs, one per segment: `[u32; 2]` normally, or
+    /// `[u64; 2]` if `wide_indices` is true.
+    pub segments: String,
+    /// If true, `segments` stores `[u64; 2]` instead of `[u32; 2]`, for line sets with more than
+    /// [`u32::MAX`] vertices. Off by default, since it doubles the size of the segment array for
+    /// the overwhelming majority of line sets that don't need it.
+    #[serde(default)]
+    pub wide_indices: bool,
+    /// Optional uniform origin added to every vertex.
+    pub origin: [f64; 3],
+}
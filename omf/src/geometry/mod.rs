@@ -0,0 +1,49 @@
+//! Geometry types describing the shape of an [`crate::Element`].
+
+mod block_model;
+mod composite;
+mod grid_surface;
+mod index;
+mod line_interp;
+mod line_set;
+mod point_set;
+mod slice;
+mod sparse;
+mod surface;
+mod tensor_grid;
+#[cfg(all(any(feature = "parquet", feature = "parquet-read"), not(feature = "zip-read-only")))]
+mod weld;
+mod winding;
+
+pub use block_model::BlockModel;
+pub use composite::Composite;
+pub use grid_surface::{GridSpacing, GridSurface};
+pub use index::{validate_subblock_parents, zip_parent_linear, BlockIndex, SubblockIndex};
+pub use line_interp::{interpolate_at_point, interpolate_segment_value};
+pub use line_set::LineSet;
+pub use point_set::PointSet;
+pub use slice::{slice_block_model, BlockModelSlice, SliceAxis};
+pub use sparse::{densify, sparsify, validate_sparse_indices, SparseBlocks};
+pub use surface::Surface;
+pub use tensor_grid::TensorAxis;
+#[cfg(all(any(feature = "parquet", feature = "parquet-read"), not(feature = "zip-read-only")))]
+pub use weld::weld_surface;
+pub use winding::{check_winding, normalize_winding, WindingReport};
+
+use serde::{Deserialize, Serialize};
+
+/// The geometry of an element. Each variant owns the arrays that define its shape; per-vertex
+/// or per-face attributes live on the owning [`crate::Element`] instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Geometry {
+    PointSet(PointSet),
+    LineSet(LineSet),
+    Surface(Surface),
+    /// A composite of nested child elements. See [`Composite`].
+    Composite(Composite),
+    /// A regular block model. See [`BlockModel`].
+    BlockModel(BlockModel),
+    /// A 2D grid surface, or flat 2D plan map. See [`GridSurface`].
+    GridSurface(GridSurface),
+}
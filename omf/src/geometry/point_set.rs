@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// A set of 3D points, with no implied connectivity between them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointSet {
+    /// Reference to the array of `[f64; 3]` vertex positions in the file's data section.
+    pub vertices: String,
+    /// Optional uniform origin added to every vertex.
+    pub origin: [f64; 3],
+}
@@ -0,0 +1,94 @@
+use super::{BlockIndex, BlockModel, GridSpacing, GridSurface};
+
+/// A 2D grid of values sliced out of a [`BlockModel`] at a fixed index along one axis, e.g. for
+/// plan-view or cross-section display.
+pub struct BlockModelSlice {
+    /// The 2D grid geometry the slice's values sit on.
+    pub grid: GridSurface,
+    /// The sliced values, in row-major order matching `grid`.
+    pub values: Vec<f64>,
+}
+
+/// Which axis a slice is taken perpendicular to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceAxis {
+    I,
+    J,
+    K,
+}
+
+/// Slices `values` (one per block, in the same linearized order as [`BlockIndex::to_linear`])
+/// out of `model` at a fixed index along `axis`, producing a 2D grid.
+pub fn slice_block_model(
+    model: &BlockModel,
+    values: &[f64],
+    axis: SliceAxis,
+    index: u32,
+) -> Option<BlockModelSlice> {
+    let [ni, nj, nk] = model.count;
+    let (u_count, u_size, v_count, v_size, axis_u, axis_v, origin) = match axis {
+        SliceAxis::I => (
+            nj,
+            model.size[1],
+            nk,
+            model.size[2],
+            model.axes[1],
+            model.axes[2],
+            offset_origin(model, [index, 0, 0]),
+        ),
+        SliceAxis::J => (
+            ni,
+            model.size[0],
+            nk,
+            model.size[2],
+            model.axes[0],
+            model.axes[2],
+            offset_origin(model, [0, index, 0]),
+        ),
+        SliceAxis::K => (
+            ni,
+            model.size[0],
+            nj,
+            model.size[1],
+            model.axes[0],
+            model.axes[1],
+            offset_origin(model, [0, 0, index]),
+        ),
+    };
+
+    let mut sliced = Vec::with_capacity((u_count * v_count) as usize);
+    for u in 0..u_count {
+        for v in 0..v_count {
+            let block = match axis {
+                SliceAxis::I => BlockIndex([index, u, v]),
+                SliceAxis::J => BlockIndex([u, index, v]),
+                SliceAxis::K => BlockIndex([u, v, index]),
+            };
+            let linear = block.to_linear(model)?;
+            sliced.push(values[linear as usize]);
+        }
+    }
+
+    Some(BlockModelSlice {
+        grid: GridSurface {
+            origin,
+            axis_u,
+            axis_v,
+            spacing_u: GridSpacing::Regular { count: u_count, size: u_size },
+            spacing_v: GridSpacing::Regular { count: v_count, size: v_size },
+            vertical_offsets: None,
+        },
+        values: sliced,
+    })
+}
+
+fn offset_origin(model: &BlockModel, block: [u32; 3]) -> [f64; 3] {
+    let mut origin = model.origin;
+    for axis in 0..3 {
+        let offset = block[axis] as f64 * model.size[axis];
+        for i in 0..3 {
+            origin[i] += model.axes[axis][i] * offset;
+        }
+    }
+    origin
+}
@@ -0,0 +1,56 @@
+//! Sparse storage for regular block models that are mostly empty, e.g. open-pit models where
+//! more than 90% of blocks are air. Rather than storing an attribute row for every block in the
+//! grid, [`SparseBlocks`] lets a [`super::BlockModel`] declare the (much smaller) set of blocks
+//! that actually exist; attribute value arrays then have one row per occupied block, in the same
+//! order as [`SparseBlocks::indices`], instead of one row per block in the whole grid.
+
+use serde::{Deserialize, Serialize};
+
+use super::index::BlockIndex;
+use super::BlockModel;
+
+/// References the array of occupied block indices for a [`BlockModel`]. Present on
+/// [`BlockModel::sparse`] to opt a block model into sparse storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparseBlocks {
+    /// Reference to the array of `u64` linear block indices (see [`BlockIndex::to_linear`]) that
+    /// are occupied, in strictly increasing order. Every attribute on the owning element has one
+    /// value per entry in this array, in the same order.
+    pub indices: String,
+}
+
+/// Checks that `indices` are a valid occupied-block list for `model`: strictly increasing (which
+/// also rules out duplicates) and in range.
+pub fn validate_sparse_indices(indices: &[u64], model: &BlockModel) -> Result<(), String> {
+    let block_count = model.block_count();
+    let mut previous: Option<u64> = None;
+    for &index in indices {
+        if index >= block_count {
+            return Err(format!("sparse block index {index} is out of range for a model with {block_count} blocks"));
+        }
+        if let Some(previous) = previous {
+            if index <= previous {
+                return Err("sparse block indices must be strictly increasing".to_string());
+            }
+        }
+        previous = Some(index);
+    }
+    Ok(())
+}
+
+/// Expands `sparse_values` (one per entry in `indices`) into a dense array with one entry per
+/// block in a model of `block_count` blocks, filling unoccupied blocks with `default`.
+pub fn densify<T: Clone>(block_count: u64, indices: &[u64], sparse_values: &[T], default: T) -> Vec<T> {
+    let mut dense = vec![default; block_count as usize];
+    for (&index, value) in indices.iter().zip(sparse_values) {
+        if let Some(slot) = dense.get_mut(index as usize) {
+            *slot = value.clone();
+        }
+    }
+    dense
+}
+
+/// The inverse of [`densify`]: keeps only the dense values at `indices`, in the same order.
+pub fn sparsify<T: Clone>(indices: &[u64], dense_values: &[T]) -> Vec<T> {
+    indices.iter().filter_map(|&index| dense_values.get(index as usize).cloned()).collect()
+}
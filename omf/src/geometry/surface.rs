@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// A triangulated surface, defined by a vertex array and a triangle index array.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Surface {
+    /// Reference to the array of `[f64; 3]` vertex positions in the file's data section.
+    pub vertices: String,
+    /// Reference to the array of vertex index triples, one per triangle: `[u32; 3]` normally, or
+    /// `[u64; 3]` if `wide_indices` is true.
+    pub triangles: String,
+    /// If true, `triangles` stores `[u64; 3]` instead of `[u32; 3]`, for meshes with more than
+    /// [`u32::MAX`] vertices (merged photogrammetry scenes can get there). Off by default, since
+    /// it doubles the size of the triangle array for the overwhelming majority of meshes that
+    /// don't need it.
+    #[serde(default)]
+    pub wide_indices: bool,
+    /// True if this surface is a closed, watertight shell representing a solid volume (a stope, a
+    /// pit design, an ore body) rather than an open surface like a topography. Set by
+    /// [`crate::file::Writer::write_solid`], which validates closure and winding consistency
+    /// first (see [`crate::validate::check_solid`]) so a reader can trust the flag instead of
+    /// re-deriving it from the triangle array.
+    #[serde(default)]
+    pub closed: bool,
+    /// Optional uniform origin added to every vertex.
+    pub origin: [f64; 3],
+}
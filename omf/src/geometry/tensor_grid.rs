@@ -0,0 +1,47 @@
+/// A tensor grid axis: a list of consecutive cell sizes rather than one uniform size, letting
+/// cells vary in width along that axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorAxis(pub Vec<f64>);
+
+impl TensorAxis {
+    /// Builds a tensor axis from boundary coordinates (`n + 1` values for `n` cells), which is
+    /// how tensor grids are usually specified by geological modeling software, rather than the
+    /// cell-size form OMF stores. Boundaries must be monotonically increasing.
+    pub fn from_boundaries(boundaries: &[f64]) -> Option<Self> {
+        if boundaries.len() < 2 {
+            return None;
+        }
+        let mut sizes = Vec::with_capacity(boundaries.len() - 1);
+        for pair in boundaries.windows(2) {
+            let size = pair[1] - pair[0];
+            if size <= 0.0 {
+                return None;
+            }
+            sizes.push(size);
+        }
+        Some(Self(sizes))
+    }
+
+    /// The boundary coordinates of this axis relative to zero, i.e. the inverse of
+    /// [`TensorAxis::from_boundaries`] (up to the choice of origin).
+    pub fn to_boundaries(&self) -> Vec<f64> {
+        let mut boundaries = Vec::with_capacity(self.0.len() + 1);
+        let mut position = 0.0;
+        boundaries.push(position);
+        for &size in &self.0 {
+            position += size;
+            boundaries.push(position);
+        }
+        boundaries
+    }
+
+    /// Number of cells along this axis.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// True if this axis has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
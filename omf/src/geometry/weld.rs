@@ -0,0 +1,70 @@
+//! Utility for merging duplicate vertices in a triangulated surface.
+
+use std::collections::HashMap;
+
+use crate::file::{Reader, Writer};
+use crate::geometry::Surface;
+use crate::Result;
+
+/// Reads the vertex and triangle arrays for `surface` from `reader`, merges vertices whose
+/// coordinates round to the same multiple of `tolerance` on every axis, re-indexes the
+/// triangles, and writes the welded arrays through `writer`, returning a new [`Surface`]
+/// pointing at them. Two vertices closer than `tolerance` that round to different multiples
+/// (either side of a rounding boundary) are not merged.
+///
+/// Meshes exported from CAD tools frequently arrive with duplicate vertices at shared edges,
+/// which then fail closed/manifold topology checks; welding with a small tolerance (e.g. the
+/// export tool's own rounding error) fixes that without changing the mesh's shape.
+pub fn weld_surface<R: std::io::Read + std::io::Seek, W: std::io::Write + std::io::Seek>(
+    reader: &mut Reader<R>,
+    surface: &Surface,
+    tolerance: f64,
+    writer: &mut Writer<W>,
+) -> Result<Surface> {
+    if surface.wide_indices {
+        return Err(crate::Error::Validation(
+            "weld_surface does not yet support wide_indices surfaces (more than u32::MAX vertices)".to_string(),
+        ));
+    }
+    let vertices = reader.read_array_f64x3(&surface.vertices)?;
+    let triangles = reader.read_array_u32x3(&surface.triangles)?;
+
+    let key = |v: &[f64; 3]| -> (i64, i64, i64) {
+        let scale = 1.0 / tolerance.max(f64::EPSILON);
+        (
+            (v[0] * scale).round() as i64,
+            (v[1] * scale).round() as i64,
+            (v[2] * scale).round() as i64,
+        )
+    };
+
+    let mut welded_vertices = Vec::new();
+    let mut index_map = Vec::with_capacity(vertices.len());
+    let mut seen: HashMap<(i64, i64, i64), u32> = HashMap::new();
+    for vertex in &vertices {
+        let k = key(vertex);
+        let index = *seen.entry(k).or_insert_with(|| {
+            welded_vertices.push(*vertex);
+            (welded_vertices.len() - 1) as u32
+        });
+        index_map.push(index);
+    }
+
+    let welded_triangles: Vec<[u32; 3]> = triangles
+        .iter()
+        .map(|t| [index_map[t[0] as usize], index_map[t[1] as usize], index_map[t[2] as usize]])
+        .collect();
+
+    let vertices_name = format!("{}-welded-vertices", surface.vertices);
+    let triangles_name = format!("{}-welded-triangles", surface.triangles);
+    writer.write_array(&vertices_name, bytemuck::cast_slice(&welded_vertices))?;
+    writer.write_array(&triangles_name, bytemuck::cast_slice(&welded_triangles))?;
+
+    Ok(Surface {
+        vertices: vertices_name,
+        triangles: triangles_name,
+        wide_indices: false,
+        closed: surface.closed,
+        origin: surface.origin,
+    })
+}
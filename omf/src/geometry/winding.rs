@@ -0,0 +1,153 @@
+//! Detecting and fixing inconsistent triangle winding on closed surfaces.
+
+/// Result of [`normalize_winding`]: how many triangles were flipped to make winding consistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindingReport {
+    /// Total number of triangles inspected.
+    pub total: usize,
+    /// Number of triangles whose winding was flipped (or, in [`check_winding`], that disagree
+    /// with the majority orientation).
+    pub flipped: usize,
+}
+
+/// Checks a closed surface's triangle winding for consistency without modifying it, using each
+/// triangle's edges: a consistently wound closed surface has every edge appear exactly once in
+/// each direction. Triangles that don't could indicate mixed winding, which silently flips the
+/// sign of volumes computed from the surface.
+pub fn check_winding(triangles: &[[u32; 3]]) -> WindingReport {
+    normalize_winding(&mut triangles.to_vec(), true)
+}
+
+/// Normalizes `triangles` in place so winding is consistent across the whole (assumed closed)
+/// surface, flipping triangles as needed. If `dry_run` is true, no triangle is actually mutated
+/// and the returned count is only a report of how many would be.
+///
+/// Works by flood-filling a settled orientation outward from an arbitrary seed triangle in each
+/// connected component: a triangle is only flipped if it disagrees with an already-settled
+/// neighbor across their shared edge, never both sides of a disagreement at once. Flipping every
+/// triangle that has any disagreeing edge (an earlier version of this function's approach) can't
+/// converge, since it flips both triangles on each side of a conflict and just moves the conflict
+/// rather than resolving it. Within each component, the orientation most of its triangles
+/// already have is kept, so only the minority is flipped.
+pub fn normalize_winding(triangles: &mut [[u32; 3]], dry_run: bool) -> WindingReport {
+    use std::collections::{HashMap, VecDeque};
+
+    let n = triangles.len();
+
+    // Every triangle that uses a given undirected edge, tagged with the direction it uses.
+    let mut edge_to_triangles: HashMap<(u32, u32), Vec<(usize, (u32, u32))>> = HashMap::new();
+    for (i, t) in triangles.iter().enumerate() {
+        for &(a, b) in &[(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_to_triangles.entry(key).or_default().push((i, (a, b)));
+        }
+    }
+
+    // Adjacency between triangles sharing an edge, tagged with whether their two directed edges
+    // are the same (a real conflict) rather than reverses of each other (consistent).
+    let mut adjacency: Vec<Vec<(usize, bool)>> = vec![Vec::new(); n];
+    for sharing in edge_to_triangles.values() {
+        for a in 0..sharing.len() {
+            for b in (a + 1)..sharing.len() {
+                let (i, direction_i) = sharing[a];
+                let (j, direction_j) = sharing[b];
+                let conflicting = direction_i == direction_j;
+                adjacency[i].push((j, conflicting));
+                adjacency[j].push((i, conflicting));
+            }
+        }
+    }
+
+    let mut should_flip = vec![false; n];
+    let mut visited = vec![false; n];
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut component = vec![start];
+        let mut queue = VecDeque::from([start]);
+        while let Some(i) = queue.pop_front() {
+            for &(neighbor, conflicting) in &adjacency[i] {
+                if visited[neighbor] {
+                    continue;
+                }
+                should_flip[neighbor] = should_flip[i] ^ conflicting;
+                visited[neighbor] = true;
+                queue.push_back(neighbor);
+                component.push(neighbor);
+            }
+        }
+        // Keep whichever orientation most of the component already has, so only the minority
+        // is flipped.
+        if component.iter().filter(|&&t| should_flip[t]).count() * 2 > component.len() {
+            for &t in &component {
+                should_flip[t] = !should_flip[t];
+            }
+        }
+    }
+
+    let flipped = should_flip.iter().filter(|&&flip| flip).count();
+    if !dry_run {
+        for (t, &flip) in triangles.iter_mut().zip(&should_flip) {
+            if flip {
+                t.swap(1, 2);
+            }
+        }
+    }
+    WindingReport { total: n, flipped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A closed, consistently outward-wound tetrahedron: every directed edge should appear
+    /// exactly once, so nothing should be reported as flipped.
+    const TETRAHEDRON: [[u32; 3]; 4] = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+
+    #[test]
+    fn consistently_wound_tetrahedron_has_no_disagreements() {
+        assert_eq!(check_winding(&TETRAHEDRON), WindingReport { total: 4, flipped: 0 });
+    }
+
+    #[test]
+    fn consistently_wound_quad_has_no_disagreements() {
+        let quad = [[0, 1, 2], [0, 2, 3]];
+        assert_eq!(check_winding(&quad), WindingReport { total: 2, flipped: 0 });
+    }
+
+    #[test]
+    fn oppositely_wound_triangle_is_flagged_and_fixed_without_a_dry_run() {
+        // The second triangle is wound the opposite way to the first along their shared edge:
+        // both produce the directed edge (2, 0) rather than one producing its reverse.
+        let original = [[0, 1, 2], [0, 3, 2]];
+
+        let mut triangles = original;
+        let report = normalize_winding(&mut triangles, true);
+        assert_eq!(report, WindingReport { total: 2, flipped: 1 });
+        assert_eq!(triangles, original, "a dry run must not mutate the triangles");
+
+        normalize_winding(&mut triangles, false);
+        assert_ne!(triangles, original, "a real run should flip the disagreeing triangle");
+        assert_eq!(check_winding(&triangles).flipped, 0, "the result must actually be consistently wound");
+    }
+
+    #[test]
+    fn one_flipped_face_on_a_tetrahedron_converges_to_fully_consistent_winding() {
+        // Face 3 of `TETRAHEDRON` ([1, 3, 2]) is reversed to [1, 2, 3], creating disagreements
+        // across all three of its shared edges with the other three (still consistent) faces.
+        let mut triangles = TETRAHEDRON;
+        triangles[3] = [1, 2, 3];
+        assert_ne!(check_winding(&triangles).flipped, 0, "the flipped face should be detected");
+
+        // A naive "flip every triangle touching a disagreement" fix would flip all four faces
+        // here (each shares an edge with the one flipped face) and oscillate forever without ever
+        // reaching a consistent state; flood-filling from a settled seed converges in one pass by
+        // flipping only the one face that actually disagrees with its already-settled neighbors.
+        let report = normalize_winding(&mut triangles, false);
+        assert_eq!(report.flipped, 1);
+        assert_eq!(triangles, TETRAHEDRON);
+        assert_eq!(check_winding(&triangles), WindingReport { total: 4, flipped: 0 });
+    }
+}
@@ -0,0 +1,86 @@
+//! Rust library for reading and writing files in Open Mining Format 2.0.
+//!
+//! See the [OMF specification](https://github.com/gmggroup/omf-rust) for details of the file
+//! format itself. This crate provides [`file::Reader`] and [`file::Writer`] for working with
+//! `.omf` files, plus the data model types ([`Project`], [`Element`], [`Attribute`], and the
+//! geometry and attribute-data types under [`geometry`] and [`attribute`]).
+//!
+//! Several cargo features let embedders trim the dependency footprint down to just what they
+//! need:
+//! - `parquet-read` (on by default) gates decoding of array contents. With it disabled,
+//!   [`file::Reader`] still parses `project.json` and lists array members via
+//!   [`file::Reader::list_arrays`], but can't read array values back out — useful for minimal
+//!   tooling (catalog indexers, web metadata scrapers) that only needs a file's structure and
+//!   wants to avoid the Parquet/Arrow dependency tree.
+//! - `parquet-write` (on by default) gates [`file::Writer`] helpers that compute derived data
+//!   before writing it (quantization, array summaries, winding normalization), as opposed to
+//!   writing caller-supplied bytes as-is.
+//! - `parquet` (on by default) is a convenience alias enabling both of the above, kept for
+//!   crates that don't need the split.
+//! - `zip-read-only` (off by default) removes [`file::Writer`] entirely, for embedders that only
+//!   ever read files and don't want to pull in the zip-writing half of the dependency tree.
+//! - `samples` (off by default) adds [`samples`], which generates small realistic sample
+//!   projects for tutorials and demos.
+//! - `mem-profile` (off by default) adds [`memory`], letting callers measure peak memory used
+//!   while reading a project after installing its `TrackingAllocator` as the process's global
+//!   allocator.
+//! - `sysinfo` (off by default) lets [`Limits::recommended`] scale its `json_bytes` limit to the
+//!   host's total memory instead of falling back to [`Limits::default`].
+//! - `parallel` (off by default) adds [`file::ParallelArrayReader`], which decodes several array
+//!   members of a memory-mapped file concurrently instead of taking turns through one
+//!   `zip::ZipArchive` like [`file::Reader`] does.
+//!
+//! `wasm32-unknown-unknown` targets are supported by using [`file::Reader::new`] and
+//! [`file::Writer::new_in_memory`] (or any other `Read + Seek` / `Write + Seek` byte buffer)
+//! instead of the path-based constructors ([`file::Reader::open`], [`file::Writer::create`]),
+//! which need a filesystem. The `mmap` and `parallel` features both depend on OS memory-mapping
+//! and are compiled out on `wasm32-unknown-unknown` regardless of whether they're enabled.
+
+pub mod attribute;
+pub mod batch;
+pub mod cancel;
+pub mod changelog;
+pub mod classification;
+pub mod compatibility;
+pub mod convention;
+pub mod crs;
+#[cfg(any(feature = "parquet", feature = "parquet-read"))]
+pub mod data;
+pub mod drillhole;
+pub mod error;
+pub mod file;
+pub mod geology;
+pub mod geometry;
+pub mod limits;
+#[cfg(feature = "mem-profile")]
+pub mod memory;
+pub mod metadata;
+pub mod omf1;
+pub mod problem;
+pub mod project;
+pub mod quantization;
+#[cfg(all(any(feature = "parquet", feature = "parquet-read"), not(feature = "zip-read-only")))]
+pub mod repair;
+#[cfg(all(
+    any(feature = "parquet", feature = "parquet-read"),
+    any(feature = "parquet", feature = "parquet-write"),
+    not(feature = "zip-read-only")
+))]
+pub mod resample;
+#[cfg(all(feature = "samples", any(feature = "parquet", feature = "parquet-write"), not(feature = "zip-read-only")))]
+pub mod samples;
+pub mod survey;
+#[cfg(any(feature = "parquet", feature = "parquet-read"))]
+pub mod table;
+#[cfg(feature = "test-utils")]
+pub mod testing;
+pub mod thumbnail;
+pub mod units;
+pub mod validate;
+
+pub use attribute::{Attribute, AttributeData};
+pub use crs::Crs;
+pub use error::{Error, ErrorCode, Result};
+pub use geometry::Geometry;
+pub use limits::Limits;
+pub use project::{BoundingBox, Element, Project};
@@ -0,0 +1,49 @@
+//! Configurable limits applied while reading files, to guard against malicious or corrupt input.
+
+/// Limits applied by [`crate::file::Reader`] to bound memory and time spent on untrusted files.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// Maximum size in bytes of the project JSON document.
+    pub json_bytes: u64,
+    /// Maximum number of elements allowed in a project.
+    pub max_elements: usize,
+    /// Maximum number of attributes allowed on a single element.
+    pub max_attributes_per_element: usize,
+    /// Maximum size in bytes of a single thumbnail image (see [`crate::thumbnail`]).
+    pub max_thumbnail_bytes: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            json_bytes: 1 << 30,
+            max_elements: 100_000,
+            max_attributes_per_element: 10_000,
+            max_thumbnail_bytes: 512 << 10,
+        }
+    }
+}
+
+impl Limits {
+    /// Computes limits scaled to the machine's available memory, for callers (the Python and C
+    /// bindings, mainly) that would otherwise have to pick one fixed [`Default`] for both a
+    /// developer's workstation and a memory-constrained embedded plugin. Only `json_bytes` is
+    /// scaled, since the other limits bound structural complexity rather than memory directly.
+    ///
+    /// With the `sysinfo` feature enabled, `json_bytes` is set to one eighth of total system
+    /// memory, clamped between the [`Default`] value and 8 GiB. Without it (or if memory can't be
+    /// detected), falls back to [`Default::default`].
+    pub fn recommended() -> Self {
+        #[cfg(feature = "sysinfo")]
+        {
+            let mut system = sysinfo::System::new();
+            system.refresh_memory();
+            let total_bytes = system.total_memory();
+            if total_bytes > 0 {
+                let json_bytes = (total_bytes / 8).clamp(Self::default().json_bytes, 8 << 30);
+                return Self { json_bytes, ..Self::default() };
+            }
+        }
+        Self::default()
+    }
+}
@@ -0,0 +1,81 @@
+//! Optional instrumentation of peak memory usage while reading a project or an array, so callers
+//! can right-size [`crate::Limits`] values and hosts can plan plugin memory budgets from actual
+//! data instead of guesswork.
+//!
+//! Requires the `mem-profile` feature and the caller to install [`TrackingAllocator`] as the
+//! process's global allocator:
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: omf::memory::TrackingAllocator<std::alloc::System> =
+//!     omf::memory::TrackingAllocator::new(std::alloc::System);
+//! ```
+//! Off by default: wrapping the global allocator adds an atomic increment/decrement to every
+//! allocation and deallocation in the process, whether or not anyone is asking for a report.
+
+#[cfg(feature = "mem-profile")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "mem-profile")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "mem-profile")]
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "mem-profile")]
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A `#[global_allocator]` wrapper that tracks outstanding allocated bytes across the whole
+/// process, so [`measure`] can report the peak reached during a call.
+#[cfg(feature = "mem-profile")]
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+#[cfg(feature = "mem-profile")]
+impl<A> TrackingAllocator<A> {
+    /// Wraps `inner`, typically [`std::alloc::System`].
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(feature = "mem-profile")]
+// SAFETY: every call is forwarded unchanged to `inner`, which is itself a valid `GlobalAlloc`;
+// the atomic bookkeeping around it can't affect the returned pointer's validity.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT_BYTES.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        self.inner.dealloc(ptr, layout);
+    }
+}
+
+/// Peak memory used, in bytes, during whatever [`measure`] wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryReport {
+    /// The highest number of bytes allocated (but not yet freed) at any point during the call,
+    /// above the baseline already allocated when it started.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, returning its result together with the peak number of bytes allocated above the
+/// baseline at the start of the call. Meaningless (always reports zero) unless
+/// [`TrackingAllocator`] is installed as the process's `#[global_allocator]`.
+///
+/// Not safe to nest or run concurrently with another [`measure`] call on another thread: both
+/// share one process-wide peak counter, so an allocation from an unrelated concurrent call would
+/// be attributed to whichever `measure` happens to read it back first.
+#[cfg(feature = "mem-profile")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryReport) {
+    let baseline = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(baseline, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(baseline);
+    (result, MemoryReport { peak_bytes: peak as u64 })
+}
@@ -0,0 +1,35 @@
+//! Ensures metadata (project, element, and attribute `metadata` maps) serializes deterministically.
+//!
+//! [`crate::Project::metadata`], [`crate::project::Element::metadata`], and
+//! [`crate::Attribute::metadata`] are all [`std::collections::BTreeMap`], which already iterate
+//! and serialize in sorted key order. The one remaining risk is a `serde_json::Value` *inside*
+//! one of those maps whose own nested objects were built with the `preserve_order` cargo feature
+//! enabled somewhere in the dependency tree, which would make `serde_json::Map` an
+//! insertion-ordered `IndexMap` instead of a `BTreeMap`. [`canonicalize`] normalizes any such
+//! value back to sorted key order before it's written, so a project's JSON is byte-for-byte
+//! reproducible from the same logical content regardless of how it was built up in memory.
+
+use serde_json::Value;
+
+/// Recursively sorts the keys of every JSON object in `value`, in place.
+pub fn canonicalize(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.iter_mut().map(|(k, v)| {
+                canonicalize(v);
+                (k.clone(), v.clone())
+            }).collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            map.clear();
+            for (k, v) in entries {
+                map.insert(k, v);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                canonicalize(item);
+            }
+        }
+        _ => {}
+    }
+}
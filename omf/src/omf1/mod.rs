@@ -0,0 +1,18 @@
+//! Conversion of legacy OMF1 files into the current [`crate::Project`] model.
+
+mod provenance;
+
+pub use provenance::{ProvenanceEntry, PROVENANCE_METADATA_KEY};
+
+use crate::Project;
+
+/// Converts an OMF1 project (already parsed by the caller into whatever the OMF1 reader
+/// produces) into a [`Project`], recording a [`ProvenanceEntry`] documenting the conversion.
+///
+/// This crate doesn't ship an OMF1 parser itself; `source_description` should identify the
+/// input in a way that's useful to a human later, e.g. the original file path or its OMF1
+/// project name.
+pub fn convert_with_provenance(mut project: Project, source_description: &str) -> Project {
+    provenance::record(&mut project, ProvenanceEntry::converted_from_omf1(source_description));
+    project
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Project;
+
+/// The project metadata key under which the provenance chain is stored, as a JSON array of
+/// [`ProvenanceEntry`].
+pub const PROVENANCE_METADATA_KEY: &str = "provenance";
+
+/// One step in a project's history, e.g. having been converted from an older format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceEntry {
+    /// What happened at this step, e.g. `"converted_from_omf1"`.
+    pub action: String,
+    /// A human-readable description of the source, e.g. the original file path.
+    pub source: String,
+}
+
+impl ProvenanceEntry {
+    /// Builds the entry recorded when converting an OMF1 file to the current format.
+    pub fn converted_from_omf1(source: &str) -> Self {
+        Self { action: "converted_from_omf1".to_string(), source: source.to_string() }
+    }
+}
+
+/// Appends `entry` to `project`'s provenance chain in its metadata, creating the chain if this
+/// is the first entry.
+pub fn record(project: &mut Project, entry: ProvenanceEntry) {
+    let mut chain: Vec<ProvenanceEntry> = project
+        .metadata
+        .get(PROVENANCE_METADATA_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    chain.push(entry);
+    project
+        .metadata
+        .insert(PROVENANCE_METADATA_KEY.to_string(), serde_json::to_value(chain).unwrap());
+}
@@ -0,0 +1,275 @@
+//! Machine-readable validation output shared by [`crate::file::Reader::validate`] and the
+//! checks [`crate::file::Writer::finish`] runs before writing (CRS validity, duplicate ids,
+//! composite convention conformance).
+//!
+//! Every individual check used to just report a `String` description, with no way to tell a
+//! caller "treat this one category as fatal" or "stop after the first hundred of these" short of
+//! parsing the message. [`Problem`] gives each one a stable [`Reason`] code and [`Severity`], and
+//! [`ValidationOptions`] plus [`ProblemCollector`] let a caller override either per reason and
+//! cap how many of each are collected, so CI pipelines can enforce stricter or looser policies
+//! than the crate's defaults.
+//!
+//! Surfaced through `omf-python` as `Writer.set_validation_options`. The `ffi` crate has no C
+//! `Reader`/`Writer` handle to hang a matching accessor off (see its module doc), so it doesn't
+//! get one either.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// How serious a [`Problem`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Worth surfacing to the user, but doesn't stop the read or write.
+    Warning,
+    /// Stops the operation: [`crate::file::Reader::validate`] and [`crate::file::Writer::finish`]
+    /// fail if any collected [`Problem`] has this severity after [`ValidationOptions`] overrides
+    /// are applied.
+    Error,
+}
+
+/// A stable category for a kind of validation problem, used as [`Problem::reason`] and matched
+/// against [`ValidationOptions::severity_overrides`]/[`ValidationOptions::category_limits`].
+///
+/// New variants are additive; match on this with a wildcard arm rather than exhaustively, since a
+/// future crate version may add one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Reason {
+    /// `project.json` wasn't valid UTF-8 and was decoded leniently (see
+    /// [`crate::file::Reader::with_lenient_encoding`]).
+    NonUtf8ProjectJson,
+    /// An attribute's [`crate::AttributeData`] is a type this version of the crate doesn't
+    /// recognize.
+    UnknownAttributeType,
+    /// An attribute's recorded units (see [`crate::units`]) aren't in the controlled vocabulary.
+    UnrecognizedUnits,
+    /// A texcoord attribute's values range far outside the expected `[0, 1]`.
+    SuspiciousTexcoordRange,
+    /// A number attribute is almost entirely null.
+    MostlyNullAttribute,
+    /// A `coordinate_reference_system` field failed [`crate::Crs::validate`].
+    InvalidCrs,
+    /// A composite's declared convention (see [`crate::convention`]) rejected it.
+    ConventionViolation,
+    /// Two elements, or two attributes, shared the same [`crate::Element::id`]/
+    /// [`crate::Attribute::id`].
+    DuplicateId,
+    /// A [`crate::geometry::Surface`] failed one of the [`crate::validate`] topology checks.
+    InvalidTopology,
+    /// `project.json` had an object field this version of the crate doesn't recognize. See
+    /// [`crate::file::Reader::with_strict_fields`].
+    UnrecognizedField,
+}
+
+impl Reason {
+    /// This reason's [`Severity`] before any [`ValidationOptions`] override.
+    pub fn default_severity(self) -> Severity {
+        match self {
+            Reason::InvalidCrs | Reason::ConventionViolation | Reason::DuplicateId => Severity::Error,
+            Reason::NonUtf8ProjectJson
+            | Reason::UnknownAttributeType
+            | Reason::UnrecognizedUnits
+            | Reason::SuspiciousTexcoordRange
+            | Reason::MostlyNullAttribute
+            | Reason::InvalidTopology
+            | Reason::UnrecognizedField => Severity::Warning,
+        }
+    }
+
+    /// True if checking this reason requires decoding array contents rather than just
+    /// `project.json`, and so is skipped by [`ValidationOptions::skip_expensive_checks`].
+    ///
+    /// [`Reason::UnrecognizedField`] counts as expensive too: it isn't array decoding, but it
+    /// does mean parsing and re-serializing `project.json` a second time just to diff against the
+    /// original, worth skipping for a caller in a hurry the same as an array-decoding check.
+    pub fn is_expensive(self) -> bool {
+        matches!(
+            self,
+            Reason::SuspiciousTexcoordRange
+                | Reason::MostlyNullAttribute
+                | Reason::InvalidTopology
+                | Reason::UnrecognizedField
+        )
+    }
+
+    /// A stable `SCREAMING_SNAKE_CASE` code identifying this reason, e.g. `"INVALID_CRS"`.
+    ///
+    /// Equal to the string this variant serializes to (see the `#[serde(rename_all = ...)]` on
+    /// [`Reason`] itself); exposed as its own method so integrators building a UI around these
+    /// codes have something to name in their own docs instead of pointing at a serde attribute.
+    pub fn code(self) -> &'static str {
+        match self {
+            Reason::NonUtf8ProjectJson => "NON_UTF8_PROJECT_JSON",
+            Reason::UnknownAttributeType => "UNKNOWN_ATTRIBUTE_TYPE",
+            Reason::UnrecognizedUnits => "UNRECOGNIZED_UNITS",
+            Reason::SuspiciousTexcoordRange => "SUSPICIOUS_TEXCOORD_RANGE",
+            Reason::MostlyNullAttribute => "MOSTLY_NULL_ATTRIBUTE",
+            Reason::InvalidCrs => "INVALID_CRS",
+            Reason::ConventionViolation => "CONVENTION_VIOLATION",
+            Reason::DuplicateId => "DUPLICATE_ID",
+            Reason::InvalidTopology => "INVALID_TOPOLOGY",
+            Reason::UnrecognizedField => "UNRECOGNIZED_FIELD",
+        }
+    }
+}
+
+/// One validation finding: a [`Reason`] code, its effective [`Severity`], a human-readable
+/// message, and, where the check that found it walks the element tree, the location it applies
+/// to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Problem {
+    /// The stable category this problem falls under.
+    pub reason: Reason,
+    /// How serious it is, after any [`ValidationOptions`] override.
+    pub severity: Severity,
+    /// A human-readable description, e.g. naming the offending element or attribute.
+    pub message: String,
+    /// The index of the top-level [`crate::Element`] this problem applies to, if the check that
+    /// found it was scoped to one element (as opposed to, say, [`Reason::NonUtf8ProjectJson`],
+    /// which applies to the whole project).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub element_index: Option<usize>,
+    /// A dotted path to the problem, e.g. `"elements[2].attributes[0]"`, in the same style as
+    /// [`crate::project::MetadataMatch::path`], for a caller that wants to point a user (or a
+    /// diff tool) straight at the offending JSON rather than parsing [`Problem::message`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub path: Option<String>,
+}
+
+/// Options controlling how [`ProblemCollector`] filters and limits problems, configurable via
+/// [`crate::file::Reader::with_validation_options`] and
+/// [`crate::file::Writer::with_validation_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    /// Overrides [`Reason::default_severity`] for specific reasons.
+    pub severity_overrides: HashMap<Reason, Severity>,
+    /// If true, every [`Severity::Warning`] is treated as [`Severity::Error`] after
+    /// `severity_overrides` is applied.
+    pub warnings_as_errors: bool,
+    /// If true, [`ProblemCollector::record`] silently drops problems whose
+    /// [`Reason::is_expensive`] is true, so a caller in a hurry can skip checks that decode array
+    /// contents.
+    pub skip_expensive_checks: bool,
+    /// Caps how many problems of a given reason [`ProblemCollector::record`] keeps, so a badly
+    /// malformed file with (say) a thousand unrecognized-units attributes doesn't produce a
+    /// thousand-entry `Vec`. `None` (the default for a reason not listed here) means no limit.
+    pub category_limits: HashMap<Reason, usize>,
+}
+
+impl ValidationOptions {
+    /// The effective [`Severity`] for `reason` under these options.
+    pub fn severity_of(&self, reason: Reason) -> Severity {
+        let severity = self.severity_overrides.get(&reason).copied().unwrap_or_else(|| reason.default_severity());
+        if self.warnings_as_errors && severity == Severity::Warning {
+            Severity::Error
+        } else {
+            severity
+        }
+    }
+}
+
+/// Accumulates [`Problem`]s one at a time, applying a shared [`ValidationOptions`] as it goes,
+/// for callers that discover problems incrementally while walking a project (one element, one
+/// attribute, one array at a time) instead of building a full list up front.
+#[derive(Debug, Default)]
+pub struct ProblemCollector {
+    options: ValidationOptions,
+    counts: HashMap<Reason, usize>,
+    problems: Vec<Problem>,
+}
+
+impl ProblemCollector {
+    /// Creates a collector that will apply `options` to every problem it's given.
+    pub fn new(options: ValidationOptions) -> Self {
+        Self { options, counts: HashMap::new(), problems: Vec::new() }
+    }
+
+    /// Records a problem under `reason`, unless [`ValidationOptions::skip_expensive_checks`]
+    /// excludes it or its category has already hit its [`ValidationOptions::category_limits`]
+    /// cap. Returns true if it was kept.
+    pub fn record(&mut self, reason: Reason, message: impl Into<String>) -> bool {
+        self.record_at(reason, message, None, None::<String>)
+    }
+
+    /// Like [`ProblemCollector::record`], but additionally attaches `element_index` and `path`
+    /// (see [`Problem::element_index`]/[`Problem::path`]) to the recorded [`Problem`], for a
+    /// check that already knows exactly where in the element tree it found the problem.
+    pub fn record_at(
+        &mut self,
+        reason: Reason,
+        message: impl Into<String>,
+        element_index: Option<usize>,
+        path: Option<impl Into<String>>,
+    ) -> bool {
+        if self.options.skip_expensive_checks && reason.is_expensive() {
+            return false;
+        }
+        let count = self.counts.entry(reason).or_insert(0);
+        if let Some(&limit) = self.options.category_limits.get(&reason) {
+            if *count >= limit {
+                return false;
+            }
+        }
+        *count += 1;
+        self.problems.push(Problem {
+            reason,
+            severity: self.options.severity_of(reason),
+            message: message.into(),
+            element_index,
+            path: path.map(Into::into),
+        });
+        true
+    }
+
+    /// True if any collected problem has [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.problems.iter().any(|problem| problem.severity == Severity::Error)
+    }
+
+    /// Consumes the collector, returning every problem recorded so far.
+    pub fn into_problems(self) -> Problems {
+        Problems(self.problems)
+    }
+}
+
+/// A collection of [`Problem`]s, as returned by [`crate::file::Reader::validate`] and
+/// [`crate::file::Writer::finish`]. Derefs to `Vec<Problem>` for the usual slice methods; the
+/// wrapper exists so it can carry [`Problems::to_json`] without adding an inherent method to the
+/// standard library's `Vec`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Problems(pub Vec<Problem>);
+
+impl Problems {
+    /// True if any problem has [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.0.iter().any(|problem| problem.severity == Severity::Error)
+    }
+
+    /// Serializes every problem to a JSON array of objects, each with a stable `reason` code
+    /// (see [`Reason::code`]), `severity`, `message`, and, where known, `element_index`/`path`,
+    /// so an integrator can render its own diagnostics UI instead of parsing [`Problem::message`]
+    /// as English text.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Problem only contains JSON-representable types")
+    }
+}
+
+impl std::ops::Deref for Problems {
+    type Target = Vec<Problem>;
+
+    fn deref(&self) -> &Vec<Problem> {
+        &self.0
+    }
+}
+
+impl IntoIterator for Problems {
+    type Item = Problem;
+    type IntoIter = std::vec::IntoIter<Problem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,212 @@
+//! The top-level [`Project`] and [`Element`] data model.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Attribute, Crs, Geometry};
+
+/// The OMF schema version this crate reads and writes by default, per the [OMF
+/// specification](https://github.com/gmggroup/omf-rust).
+pub const CURRENT_VERSION: &str = "2.0";
+
+fn default_version() -> String {
+    CURRENT_VERSION.to_string()
+}
+
+/// True if `version` looks like a prerelease format revision, e.g. `"2.1-rc1"`, by the usual
+/// semver convention of a `-` separating the release version from a prerelease tag.
+pub fn is_prerelease_version(version: &str) -> bool {
+    version.contains('-')
+}
+
+/// The root object of an OMF file: metadata about the project plus its list of elements.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    /// Human-readable project name.
+    pub name: String,
+    /// Optional longer description of the project.
+    #[serde(default)]
+    pub description: String,
+    /// The OMF schema version this project conforms to, e.g. `"2.0"`. Defaults to
+    /// [`CURRENT_VERSION`] for projects built with [`Project::new`] or read from files written
+    /// before this field existed. See [`crate::file::Reader::with_pinned_version`] and
+    /// [`crate::file::Writer::with_pinned_version`] to require an exact version.
+    #[serde(default = "default_version")]
+    pub version: String,
+    /// The elements that make up the project.
+    #[serde(default)]
+    pub elements: Vec<Element>,
+    /// The coordinate reference system all element geometry is expressed in, unless overridden by
+    /// [`Element::coordinate_reference_system`]. `None` means the CRS is unknown or unspecified,
+    /// as with older files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coordinate_reference_system: Option<Crs>,
+    /// The union of every element's [`Element::bounding_box`], letting viewers set up a camera
+    /// and spatial index for the whole project without reading a single vertex or block array.
+    /// `None` if it hasn't been computed, as with projects written before this field existed or
+    /// without [`crate::file::Writer::with_bounding_boxes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+    /// Free-form project-level metadata, e.g. the provenance chain (see [`crate::omf1`]).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+fn new_element_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+impl Project {
+    /// Creates a new, empty project with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            version: CURRENT_VERSION.to_string(),
+            elements: Vec::new(),
+            coordinate_reference_system: None,
+            bounding_box: None,
+            metadata: Default::default(),
+        }
+    }
+
+    /// Searches every metadata map in the project (its own, each element's, and each attribute's)
+    /// for `key`, recursing into nested JSON objects and arrays, and returns every match together
+    /// with a path describing where it was found (e.g. `"elements[2].metadata.domain_code"`).
+    ///
+    /// Lets tooling answer questions like "which elements are tagged with a given domain code"
+    /// without writing the same recursive traversal over `metadata` maps every time.
+    pub fn query_metadata(&self, key: &str) -> Vec<MetadataMatch> {
+        let mut matches = Vec::new();
+        query_metadata_map(&self.metadata, "metadata", key, &mut matches);
+        for (element_index, element) in self.elements.iter().enumerate() {
+            let element_prefix = format!("elements[{element_index}].metadata");
+            query_metadata_map(&element.metadata, &element_prefix, key, &mut matches);
+            for (attribute_index, attribute) in element.attributes.iter().enumerate() {
+                let attribute_prefix =
+                    format!("elements[{element_index}].attributes[{attribute_index}].metadata");
+                query_metadata_map(&attribute.metadata, &attribute_prefix, key, &mut matches);
+            }
+        }
+        matches
+    }
+}
+
+/// One key/value pair found by [`Project::query_metadata`], together with the path describing
+/// where it was found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataMatch {
+    /// A dotted path to the match, e.g. `"elements[2].metadata.domain_code"`.
+    pub path: String,
+    /// The matched value.
+    pub value: serde_json::Value,
+}
+
+fn query_metadata_map(
+    map: &std::collections::BTreeMap<String, serde_json::Value>,
+    prefix: &str,
+    key: &str,
+    matches: &mut Vec<MetadataMatch>,
+) {
+    for (map_key, value) in map {
+        let path = format!("{prefix}.{map_key}");
+        if map_key == key {
+            matches.push(MetadataMatch { path: path.clone(), value: value.clone() });
+        }
+        query_metadata_value(value, &path, key, matches);
+    }
+}
+
+fn query_metadata_value(value: &serde_json::Value, path: &str, key: &str, matches: &mut Vec<MetadataMatch>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (object_key, nested) in object {
+                let nested_path = format!("{path}.{object_key}");
+                if object_key == key {
+                    matches.push(MetadataMatch { path: nested_path.clone(), value: nested.clone() });
+                }
+                query_metadata_value(nested, &nested_path, key, matches);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                query_metadata_value(item, &format!("{path}[{index}]"), key, matches);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A single spatial object within a [`Project`]: a name, a [`Geometry`], and its [`Attribute`]s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Element {
+    /// A stable identifier for this element, unique within its owning project and unaffected by
+    /// renaming. Synchronization tools should reference this instead of `name` to diff and
+    /// update objects across file versions. Generated if not already present when an element is
+    /// first written, mirroring [`crate::Attribute::id`].
+    #[serde(default = "new_element_id")]
+    pub id: String,
+    /// Human-readable element name.
+    pub name: String,
+    /// Optional longer description of the element.
+    #[serde(default)]
+    pub description: String,
+    /// The shape of the element.
+    pub geometry: Geometry,
+    /// Attributes attached to the element's geometry.
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+    /// The axis-aligned extent of the element's geometry in project coordinates, letting a viewer
+    /// or spatial index size itself without decoding the element's vertex or block arrays. `None`
+    /// if it hasn't been computed, as with elements written before this field existed or without
+    /// [`crate::file::Writer::with_bounding_boxes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+    /// Overrides [`Project::coordinate_reference_system`] for this element alone, for the rare
+    /// case where one element (e.g. a drillhole collar list still in a local mine grid) is
+    /// surveyed in a different system than the rest of the project. `None` means the project's
+    /// CRS applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coordinate_reference_system: Option<Crs>,
+    /// Free-form metadata, e.g. the name of a composite convention (see [`crate::convention`]).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// An axis-aligned bounding box in project coordinates, as stored on [`Project::bounding_box`]
+/// and [`Element::bounding_box`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    /// The minimum corner: the smallest x, y, and z seen.
+    pub min: [f64; 3],
+    /// The maximum corner: the largest x, y, and z seen.
+    pub max: [f64; 3],
+}
+
+impl BoundingBox {
+    /// Computes the bounding box of `points`, or `None` if `points` is empty.
+    pub fn from_points(points: &[[f64; 3]]) -> Option<Self> {
+        let mut points = points.iter();
+        let first = *points.next()?;
+        let mut bounding_box = Self { min: first, max: first };
+        for &point in points {
+            bounding_box.grow(point);
+        }
+        Some(bounding_box)
+    }
+
+    /// Expands this box, if necessary, to also contain `point`.
+    pub fn grow(&mut self, point: [f64; 3]) {
+        for axis in 0..3 {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        }
+    }
+
+    /// The smallest bounding box containing both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.grow(other.min);
+        result.grow(other.max);
+        result
+    }
+}
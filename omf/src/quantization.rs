@@ -0,0 +1,36 @@
+//! Opt-in rounding of float arrays before they're written, to improve Parquet compression.
+
+/// Precision to quantize a float array to before writing. Recorded in the attribute's metadata
+/// so readers know the value has already been rounded and by how much.
+#[derive(Debug, Clone, Copy)]
+pub enum Precision {
+    /// Round to the nearest multiple of this step, e.g. `0.001` for millimeter vertex precision.
+    Step(f64),
+    /// Round to this many significant decimal digits, e.g. `4` for typical grade values.
+    SignificantDigits(u32),
+}
+
+impl Precision {
+    /// Applies this precision to a single value.
+    pub fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Precision::Step(step) if step > 0.0 => (value / step).round() * step,
+            Precision::Step(_) => value,
+            Precision::SignificantDigits(digits) => {
+                if value == 0.0 || !value.is_finite() {
+                    return value;
+                }
+                let magnitude = value.abs().log10().floor() as i32;
+                let scale = 10f64.powi(digits as i32 - 1 - magnitude);
+                (value * scale).round() / scale
+            }
+        }
+    }
+
+    /// Applies this precision to every value in `values`, in place.
+    pub fn apply_slice(&self, values: &mut [f64]) {
+        for value in values {
+            *value = self.apply(*value);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! A high-level "validate and repair" pipeline for `.omf` files.
+//!
+//! Files produced by third-party writers occasionally have small, mechanical problems: a
+//! category index that's outside the range of its `names` list, an attribute value array that's
+//! longer than its element's vertex count, orientation vectors that
+//! aren't quite unit length, or an attribute of a type this crate doesn't recognize. None of
+//! these are worth failing the whole read over, and all of them have an obvious, safe fix.
+//! [`repair`] applies those fixes while copying a project from a [`Reader`] to a [`Writer`],
+//! and returns a [`RepairReport`] listing exactly what it changed.
+
+use std::io::{Read, Seek, Write as IoWrite};
+
+use crate::attribute::AttributeData;
+use crate::file::{Reader, Writer};
+use crate::geometry::Geometry;
+use crate::project::Element;
+use crate::{Project, Result};
+
+/// A sentinel category index meaning "no category", used when [`repair`] clamps an index that's
+/// outside the range of its attribute's `names` list.
+const NULL_CATEGORY_INDEX: u32 = u32::MAX;
+
+/// Which fixes [`repair`] is allowed to make. All enabled by default, since every fix here is
+/// safe: it can only make a file more consistent, never change what it's trying to represent.
+#[derive(Debug, Clone, Copy)]
+pub struct RepairOptions {
+    /// Clamp category attribute indices outside the range of their `names` list to
+    /// [`NULL_CATEGORY_INDEX`] instead of leaving a dangling reference.
+    pub clamp_category_indices: bool,
+    /// Drop attributes with a data type this version of the crate doesn't recognize
+    /// ([`AttributeData::Unknown`]) rather than carrying them over unread.
+    pub drop_unrecognized_attributes: bool,
+    /// Normalize [`crate::geometry::BlockModel`] axis vectors that aren't unit length.
+    pub normalize_orientations: bool,
+    /// Truncate attribute value arrays that are longer than their element's vertex count down to
+    /// that count, so readers indexing by location can't run off the end.
+    pub truncate_mismatched_attributes: bool,
+}
+
+impl Default for RepairOptions {
+    fn default() -> Self {
+        Self {
+            clamp_category_indices: true,
+            drop_unrecognized_attributes: true,
+            normalize_orientations: true,
+            truncate_mismatched_attributes: true,
+        }
+    }
+}
+
+/// One fix [`repair`] applied.
+#[derive(Debug, Clone)]
+pub struct Fix {
+    /// The element the fix was applied to.
+    pub element: String,
+    /// What was done, e.g. `"clamped 3 out-of-range category indices"`.
+    pub description: String,
+}
+
+/// A machine-readable record of everything [`repair`] changed.
+#[derive(Debug, Clone, Default)]
+pub struct RepairReport {
+    pub fixes: Vec<Fix>,
+}
+
+impl RepairReport {
+    fn record(&mut self, element: &str, description: impl Into<String>) {
+        self.fixes.push(Fix { element: element.to_string(), description: description.into() });
+    }
+}
+
+/// Reads the project out of `reader`, applies the fixes enabled in `options`, writes the
+/// resulting arrays out to `writer`, and returns the cleaned [`Project`] (still to
+/// be passed to [`Writer::finish`] by the caller) along with a [`RepairReport`] of what changed.
+pub fn repair<R: Read + Seek, W: IoWrite + Seek>(
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+    options: RepairOptions,
+) -> Result<(Project, RepairReport)> {
+    let mut project = reader.project()?;
+    let mut report = RepairReport::default();
+    for element in &mut project.elements {
+        repair_element(reader, writer, options, &mut report, element)?;
+    }
+    Ok((project, report))
+}
+
+fn repair_element<R: Read + Seek, W: IoWrite + Seek>(
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+    options: RepairOptions,
+    report: &mut RepairReport,
+    element: &mut Element,
+) -> Result<()> {
+    let vertex_count = match &element.geometry {
+        Geometry::PointSet(g) => Some(copy_vertices(reader, writer, &g.vertices)?),
+        Geometry::LineSet(g) => {
+            let count = copy_vertices(reader, writer, &g.vertices)?;
+            if g.wide_indices {
+                writer
+                    .write_array(&g.segments, bytemuck::cast_slice(&reader.read_array_of::<[u64; 2]>(&g.segments)?))?;
+            } else {
+                writer
+                    .write_array(&g.segments, bytemuck::cast_slice(&reader.read_array_of::<[u32; 2]>(&g.segments)?))?;
+            }
+            Some(count)
+        }
+        Geometry::Surface(g) => {
+            let count = copy_vertices(reader, writer, &g.vertices)?;
+            if g.wide_indices {
+                writer.write_array(
+                    &g.triangles,
+                    bytemuck::cast_slice(&reader.read_array_of::<[u64; 3]>(&g.triangles)?),
+                )?;
+            } else {
+                writer.write_array(
+                    &g.triangles,
+                    bytemuck::cast_slice(&reader.read_array_of::<[u32; 3]>(&g.triangles)?),
+                )?;
+            }
+            Some(count)
+        }
+        Geometry::BlockModel(block_model) => {
+            if options.normalize_orientations {
+                let mut changed = 0;
+                for axis in &mut block_model.axes {
+                    let length = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+                    if length > 0.0 && (length - 1.0).abs() > 1e-9 {
+                        axis[0] /= length;
+                        axis[1] /= length;
+                        axis[2] /= length;
+                        changed += 1;
+                    }
+                }
+                if changed > 0 {
+                    report.record(&element.name, format!("normalized {changed} non-unit orientation axes"));
+                }
+            }
+            if let Some(sparse) = &block_model.sparse {
+                let indices: Vec<u64> = reader.read_array_of(&sparse.indices)?;
+                writer.write_array(&sparse.indices, bytemuck::cast_slice(&indices))?;
+            }
+            None
+        }
+        Geometry::GridSurface(grid) => {
+            if let Some(vertical_offsets) = &grid.vertical_offsets {
+                let heights: Vec<f64> = reader.read_array_of(vertical_offsets)?;
+                writer.write_array(vertical_offsets, bytemuck::cast_slice(&heights))?;
+            }
+            None
+        }
+        Geometry::Composite(composite) => {
+            for child in &mut composite.elements {
+                repair_element(reader, writer, options, report, child)?;
+            }
+            None
+        }
+        _ => None,
+    };
+
+    if options.drop_unrecognized_attributes {
+        let before = element.attributes.len();
+        element.attributes.retain(|attribute| !attribute.data.is_unknown());
+        let dropped = before - element.attributes.len();
+        if dropped > 0 {
+            report.record(&element.name, format!("dropped {dropped} unrecognized attribute(s)"));
+        }
+    }
+
+    for attribute in &mut element.attributes {
+        match &mut attribute.data {
+            AttributeData::Category { values, names, .. } => {
+                let mut indices: Vec<u32> = reader.read_array_of(values)?;
+                let mut clamped = 0;
+                if options.clamp_category_indices {
+                    for index in &mut indices {
+                        if *index != NULL_CATEGORY_INDEX && *index as usize >= names.len() {
+                            *index = NULL_CATEGORY_INDEX;
+                            clamped += 1;
+                        }
+                    }
+                }
+                let truncated = truncate_to(&mut indices, vertex_count, options.truncate_mismatched_attributes);
+                if clamped > 0 {
+                    report.record(&element.name, format!("clamped {clamped} out-of-range category indices on \"{}\"", attribute.name));
+                }
+                if truncated > 0 {
+                    report.record(&element.name, format!("truncated {truncated} extra value(s) from \"{}\"", attribute.name));
+                }
+                writer.write_array(values, bytemuck::cast_slice(&indices))?;
+            }
+            AttributeData::Number { values } => {
+                let mut numbers: Vec<f64> = reader.read_array_of(values)?;
+                let truncated = truncate_to(&mut numbers, vertex_count, options.truncate_mismatched_attributes);
+                if truncated > 0 {
+                    report.record(&element.name, format!("truncated {truncated} extra value(s) from \"{}\"", attribute.name));
+                }
+                writer.write_array(values, bytemuck::cast_slice(&numbers))?;
+            }
+            AttributeData::Boolean { values } => {
+                let mut flags: Vec<u8> = reader.read_array_of(values)?;
+                let truncated = truncate_to(&mut flags, vertex_count, options.truncate_mismatched_attributes);
+                if truncated > 0 {
+                    report.record(&element.name, format!("truncated {truncated} extra value(s) from \"{}\"", attribute.name));
+                }
+                writer.write_array(values, &flags)?;
+            }
+            AttributeData::Text { values } => {
+                // Text arrays aren't fixed-width, so there's no safe way to truncate them
+                // byte-for-byte; carry them over untouched.
+                let bytes: Vec<u8> = reader.read_array_of(values)?;
+                writer.write_array(values, &bytes)?;
+            }
+            AttributeData::Texcoord { values, quantized } => {
+                let (bytes, truncated) = if *quantized {
+                    let mut coords: Vec<[u16; 2]> = reader.read_array_of(values)?;
+                    let truncated = truncate_to(&mut coords, vertex_count, options.truncate_mismatched_attributes);
+                    (bytemuck::cast_slice(&coords).to_vec(), truncated)
+                } else {
+                    let mut coords: Vec<[f32; 2]> = reader.read_array_of(values)?;
+                    let truncated = truncate_to(&mut coords, vertex_count, options.truncate_mismatched_attributes);
+                    (bytemuck::cast_slice(&coords).to_vec(), truncated)
+                };
+                if truncated > 0 {
+                    report.record(&element.name, format!("truncated {truncated} extra value(s) from \"{}\"", attribute.name));
+                }
+                writer.write_array(values, &bytes)?;
+            }
+            AttributeData::Unknown { .. } => {}
+        }
+    }
+    Ok(())
+}
+
+/// Truncates `values` down to `expected_count`, if it's known, truncation is enabled, and
+/// `values` is longer than it. Returns how many trailing values were dropped.
+fn truncate_to<T>(values: &mut Vec<T>, expected_count: Option<usize>, enabled: bool) -> usize {
+    let Some(expected_count) = expected_count.filter(|_| enabled) else {
+        return 0;
+    };
+    if values.len() <= expected_count {
+        return 0;
+    }
+    let dropped = values.len() - expected_count;
+    values.truncate(expected_count);
+    dropped
+}
+
+fn copy_vertices<R: Read + Seek, W: IoWrite + Seek>(
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+    name: &str,
+) -> Result<usize> {
+    let vertices: Vec<[f64; 3]> = reader.read_array_of(name)?;
+    writer.write_array(name, bytemuck::cast_slice(&vertices))?;
+    Ok(vertices.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::geometry::{BlockModel, GridSpacing, GridSurface, SparseBlocks};
+
+    use super::*;
+
+    /// Writes `project` (with its arrays already written to `writer`) out and reopens it as a
+    /// [`Reader`] over the resulting bytes, so a test can round-trip a project through `repair`
+    /// without touching the filesystem.
+    fn write_and_reopen(writer: Writer<Cursor<Vec<u8>>>, project: &Project) -> Reader<Cursor<Vec<u8>>> {
+        let (bytes, _, _, _) = writer.finish(project).unwrap();
+        Reader::new(Cursor::new(bytes.into_inner())).unwrap()
+    }
+
+    #[test]
+    fn repair_copies_grid_surface_vertical_offsets() {
+        let mut writer = Writer::new_in_memory().unwrap();
+        let heights = vec![1.0, 2.0, 3.0, 4.0];
+        writer.write_array("heights", bytemuck::cast_slice(&heights)).unwrap();
+        let grid = GridSurface {
+            origin: [0.0, 0.0, 0.0],
+            axis_u: [1.0, 0.0, 0.0],
+            axis_v: [0.0, 1.0, 0.0],
+            spacing_u: GridSpacing::Regular { count: 1, size: 1.0 },
+            spacing_v: GridSpacing::Regular { count: 1, size: 1.0 },
+            vertical_offsets: Some("heights".to_string()),
+        };
+        let mut project = Project::new("repair test");
+        project.elements.push(Element {
+            id: String::new(),
+            name: "grid".to_string(),
+            description: String::new(),
+            geometry: Geometry::GridSurface(grid),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        let mut reader = write_and_reopen(writer, &project);
+
+        let mut repaired_writer = Writer::new_in_memory().unwrap();
+        let (repaired_project, _report) = repair(&mut reader, &mut repaired_writer, RepairOptions::default()).unwrap();
+        let mut repaired_reader = write_and_reopen(repaired_writer, &repaired_project);
+
+        let round_tripped: Vec<f64> = repaired_reader.read_array_of("heights").unwrap();
+        assert_eq!(round_tripped, heights);
+    }
+
+    #[test]
+    fn repair_copies_sparse_block_model_indices() {
+        let mut writer = Writer::new_in_memory().unwrap();
+        let indices: Vec<u64> = vec![0, 2, 5];
+        writer.write_array("occupied", bytemuck::cast_slice(&indices)).unwrap();
+        let block_model = BlockModel {
+            count: [2, 2, 2],
+            size: [1.0, 1.0, 1.0],
+            origin: [0.0, 0.0, 0.0],
+            axes: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            sparse: Some(SparseBlocks { indices: "occupied".to_string() }),
+        };
+        let mut project = Project::new("repair test");
+        project.elements.push(Element {
+            id: String::new(),
+            name: "blocks".to_string(),
+            description: String::new(),
+            geometry: Geometry::BlockModel(block_model),
+            attributes: Vec::new(),
+            bounding_box: None,
+            coordinate_reference_system: None,
+            metadata: Default::default(),
+        });
+        let mut reader = write_and_reopen(writer, &project);
+
+        let mut repaired_writer = Writer::new_in_memory().unwrap();
+        let (repaired_project, _report) = repair(&mut reader, &mut repaired_writer, RepairOptions::default()).unwrap();
+        let mut repaired_reader = write_and_reopen(repaired_writer, &repaired_project);
+
+        let round_tripped: Vec<u64> = repaired_reader.read_array_of("occupied").unwrap();
+        assert_eq!(round_tripped, indices);
+    }
+}
@@ -0,0 +1,195 @@
+//! Transferring an attribute's values from one element's locations onto another's, e.g. surface
+//! vertex grades onto nearby block centroids, or block values onto points. A constant need when
+//! moving data between different representations of roughly the same volume.
+
+use std::io::{Read, Seek, Write as IoWrite};
+
+use crate::file::{Reader, Writer};
+use crate::quantization::Precision;
+use crate::{Attribute, Result};
+
+/// How to combine nearby source values onto a target location.
+#[derive(Debug, Clone, Copy)]
+pub enum ResampleMethod {
+    /// Use the value of the single closest source point within the search radius.
+    NearestNeighbor,
+    /// Weight every source point within the search radius by `1 / distance.powf(power)`.
+    InverseDistance { power: f64 },
+}
+
+/// Resamples a numeric attribute from `source_positions`/`source_values` onto `target_positions`,
+/// searching for source points within `search_radius` of each target and combining them via
+/// `method`. A target with no source point in range gets `f64::NAN`, the same "no value" marker
+/// [`crate::file::ArraySummary`] already treats as null.
+///
+/// This is a brute-force nearest-neighbor search (no spatial index), which is fine for the
+/// hundreds-of-thousands-of-points scale typical of a single resampling job; very large transfers
+/// should pre-filter `source_positions` to a relevant region first.
+pub fn resample_values(
+    source_positions: &[[f64; 3]],
+    source_values: &[f64],
+    target_positions: &[[f64; 3]],
+    method: ResampleMethod,
+    search_radius: f64,
+) -> Vec<f64> {
+    target_positions
+        .iter()
+        .map(|&target| resample_one(source_positions, source_values, target, method, search_radius))
+        .collect()
+}
+
+fn resample_one(
+    source_positions: &[[f64; 3]],
+    source_values: &[f64],
+    target: [f64; 3],
+    method: ResampleMethod,
+    search_radius: f64,
+) -> f64 {
+    let in_range: Vec<(f64, f64)> = source_positions
+        .iter()
+        .zip(source_values)
+        .filter_map(|(&position, &value)| {
+            let distance = squared_distance(position, target).sqrt();
+            (distance <= search_radius).then_some((distance, value))
+        })
+        .collect();
+    if in_range.is_empty() {
+        return f64::NAN;
+    }
+    match method {
+        ResampleMethod::NearestNeighbor => {
+            in_range.into_iter().min_by(|a, b| a.0.total_cmp(&b.0)).unwrap().1
+        }
+        ResampleMethod::InverseDistance { power } => {
+            let mut weighted_sum = 0.0;
+            let mut weight_total = 0.0;
+            for (distance, value) in in_range {
+                // A source point exactly at the target dominates the average, rather than
+                // dividing by zero.
+                if distance == 0.0 {
+                    return value;
+                }
+                let weight = 1.0 / distance.powf(power);
+                weighted_sum += weight * value;
+                weight_total += weight;
+            }
+            weighted_sum / weight_total
+        }
+    }
+}
+
+/// Reads a numeric attribute's source positions and values from `reader`, resamples them onto
+/// `target_positions` with [`resample_values`], optionally rounds the result with `precision`,
+/// and writes it out through `writer` as a new attribute named `output_name` at `output_location`.
+#[allow(clippy::too_many_arguments)]
+pub fn resample_attribute<R: Read + Seek, W: IoWrite + Seek>(
+    reader: &mut Reader<R>,
+    source_positions_array: &str,
+    source_values_array: &str,
+    target_positions: &[[f64; 3]],
+    method: ResampleMethod,
+    search_radius: f64,
+    precision: Option<Precision>,
+    writer: &mut Writer<W>,
+    output_name: &str,
+    output_location: &str,
+) -> Result<Attribute> {
+    let source_positions: Vec<[f64; 3]> = reader.read_array_of(source_positions_array)?;
+    let source_values: Vec<f64> = reader.read_array_of(source_values_array)?;
+    let values = resample_values(&source_positions, &source_values, target_positions, method, search_radius);
+    writer.write_number_attribute(output_name, output_location, values, precision)
+}
+
+fn squared_distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::file::{Reader, Writer};
+    use crate::Project;
+
+    use super::*;
+
+    #[test]
+    fn target_with_no_source_in_range_is_nan() {
+        let values = resample_values(&[[10.0, 0.0, 0.0]], &[1.0], &[[0.0, 0.0, 0.0]], ResampleMethod::NearestNeighbor, 1.0);
+        assert!(values[0].is_nan());
+    }
+
+    #[test]
+    fn nearest_neighbor_uses_the_closest_source_value() {
+        let source_positions = [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0]];
+        let source_values = [1.0, 100.0];
+        let values =
+            resample_values(&source_positions, &source_values, &[[1.0, 0.0, 0.0]], ResampleMethod::NearestNeighbor, 20.0);
+        assert_eq!(values, vec![1.0]);
+    }
+
+    #[test]
+    fn inverse_distance_weights_by_reciprocal_distance() {
+        // Source points at distance 1 and 2 from the target, with power 1: weights 1/1 and 1/2,
+        // so the source at distance 1 counts twice as much as the one at distance 2.
+        let source_positions = [[1.0, 0.0, 0.0], [2.0, 0.0, 0.0]];
+        let source_values = [10.0, 20.0];
+        let values = resample_values(
+            &source_positions,
+            &source_values,
+            &[[0.0, 0.0, 0.0]],
+            ResampleMethod::InverseDistance { power: 1.0 },
+            5.0,
+        );
+        let expected = (10.0 / 1.0 + 20.0 / 2.0) / (1.0 / 1.0 + 1.0 / 2.0);
+        assert!((values[0] - expected).abs() < 1e-9, "expected {expected}, got {}", values[0]);
+    }
+
+    #[test]
+    fn inverse_distance_short_circuits_on_an_exact_match_instead_of_dividing_by_zero() {
+        let source_positions = [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0]];
+        let source_values = [42.0, 1000.0];
+        let values = resample_values(
+            &source_positions,
+            &source_values,
+            &[[0.0, 0.0, 0.0]],
+            ResampleMethod::InverseDistance { power: 2.0 },
+            20.0,
+        );
+        assert_eq!(values, vec![42.0]);
+    }
+
+    #[test]
+    fn resample_attribute_reads_source_arrays_and_writes_the_resampled_result() {
+        let mut writer = Writer::new_in_memory().unwrap();
+        let source_positions = [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0]];
+        let source_values = [1.0, 5.0];
+        writer.write_array("positions", bytemuck::cast_slice(&source_positions)).unwrap();
+        writer.write_array("values", bytemuck::cast_slice(&source_values)).unwrap();
+        let (bytes, ..) = writer.finish(&Project::new("resample source")).unwrap();
+
+        let mut reader = Reader::new(Cursor::new(bytes.into_inner())).unwrap();
+        let mut output_writer = Writer::new_in_memory().unwrap();
+        let attribute = resample_attribute(
+            &mut reader,
+            "positions",
+            "values",
+            &[[1.0, 0.0, 0.0]],
+            ResampleMethod::NearestNeighbor,
+            5.0,
+            None,
+            &mut output_writer,
+            "resampled",
+            "vertices",
+        )
+        .unwrap();
+
+        assert_eq!(attribute.name, "resampled");
+        assert_eq!(attribute.location, "vertices");
+        let crate::AttributeData::Number { values } = &attribute.data else { panic!("expected a number attribute") };
+        let (bytes, ..) = output_writer.finish(&Project::new("resample target")).unwrap();
+        let mut output_reader = Reader::new(Cursor::new(bytes.into_inner())).unwrap();
+        let round_tripped: Vec<f64> = output_reader.read_array_of(values).unwrap();
+        assert_eq!(round_tripped, vec![1.0]);
+    }
+}
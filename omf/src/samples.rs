@@ -0,0 +1,169 @@
+//! Generators for small, realistic sample projects, so documentation examples and downstream
+//! demos run immediately without hunting for real data files.
+//!
+//! Not enabled by default: opt in with the `samples` feature.
+
+use std::io::{Seek, Write as IoWrite};
+
+use crate::file::Writer;
+use crate::geometry::{BlockModel, Geometry, LineSet};
+use crate::quantization::Precision;
+use crate::{Element, Project, Result};
+
+/// Builds a small open-pit surface stepped into five concentric benches, with a `"bench"`
+/// attribute recording which bench each vertex sits on. Writes its vertex and triangle arrays to
+/// `writer`.
+pub fn pit_surface_with_benches<W: IoWrite + Seek>(writer: &mut Writer<W>) -> Result<Element> {
+    const RING_POINTS: usize = 16;
+    const BENCH_COUNT: usize = 5;
+    const BENCH_HEIGHT: f64 = 10.0;
+    const TOP_RADIUS: f64 = 100.0;
+    const RADIUS_STEP: f64 = 15.0;
+
+    let mut vertices = Vec::new();
+    let mut bench_of_vertex = Vec::new();
+    for bench in 0..BENCH_COUNT {
+        let radius = TOP_RADIUS - bench as f64 * RADIUS_STEP;
+        let z = -(bench as f64) * BENCH_HEIGHT;
+        for i in 0..RING_POINTS {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / RING_POINTS as f64;
+            vertices.push([radius * angle.cos(), radius * angle.sin(), z]);
+            bench_of_vertex.push(bench as f64);
+        }
+    }
+    // A single point closing the pit floor.
+    let floor_index = vertices.len() as u32;
+    vertices.push([0.0, 0.0, -((BENCH_COUNT - 1) as f64) * BENCH_HEIGHT]);
+    bench_of_vertex.push((BENCH_COUNT - 1) as f64);
+
+    let mut triangles = Vec::new();
+    for bench in 0..BENCH_COUNT - 1 {
+        let ring_start = (bench * RING_POINTS) as u32;
+        let next_start = ((bench + 1) * RING_POINTS) as u32;
+        for i in 0..RING_POINTS as u32 {
+            let j = (i + 1) % RING_POINTS as u32;
+            triangles.push([ring_start + i, next_start + i, ring_start + j]);
+            triangles.push([ring_start + j, next_start + i, next_start + j]);
+        }
+    }
+    let bottom_start = ((BENCH_COUNT - 1) * RING_POINTS) as u32;
+    for i in 0..RING_POINTS as u32 {
+        let j = (i + 1) % RING_POINTS as u32;
+        triangles.push([bottom_start + i, bottom_start + j, floor_index]);
+    }
+
+    let (surface, _winding) = writer.write_surface("pit-surface", &vertices, triangles, true)?;
+    let bench_attribute = writer.write_number_attribute("bench", "vertices", bench_of_vertex, None)?;
+
+    Ok(Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Pit surface".to_string(),
+        description: "Sample stepped pit design with five benches.".to_string(),
+        geometry: Geometry::Surface(surface),
+        attributes: vec![bench_attribute],
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata: Default::default(),
+    })
+}
+
+/// Builds a single drillhole-like line set: a straight, dipping trace split into intervals, with
+/// a `"cu_pct"` copper assay attribute that peaks partway down the hole. Writes its vertex and
+/// segment arrays to `writer`.
+pub fn drillhole_with_assays<W: IoWrite + Seek>(writer: &mut Writer<W>) -> Result<Element> {
+    const SEGMENT_COUNT: usize = 20;
+    const SEGMENT_LENGTH: f64 = 5.0;
+    let dip = 60f64.to_radians();
+
+    let vertices: Vec<[f64; 3]> = (0..=SEGMENT_COUNT)
+        .map(|i| {
+            let depth = i as f64 * SEGMENT_LENGTH;
+            [0.0, depth * dip.cos(), -depth * dip.sin()]
+        })
+        .collect();
+    let segments: Vec<[u32; 2]> = (0..SEGMENT_COUNT as u32).map(|i| [i, i + 1]).collect();
+
+    let vertices_name = "drillhole-vertices";
+    let segments_name = "drillhole-segments";
+    writer.write_array(vertices_name, bytemuck::cast_slice(&vertices))?;
+    writer.write_array(segments_name, bytemuck::cast_slice(&segments))?;
+    let geometry =
+        LineSet {
+        vertices: vertices_name.to_string(),
+        segments: segments_name.to_string(),
+        wide_indices: false,
+        origin: [0.0; 3],
+    };
+
+    // A believable grade profile: background level with a higher-grade zone around the midpoint.
+    let mid = SEGMENT_COUNT as f64 / 2.0;
+    let assays: Vec<f64> = (0..SEGMENT_COUNT)
+        .map(|i| {
+            let distance = (i as f64 - mid).abs();
+            0.2 + (1.0 - distance / mid).max(0.0) * 1.5
+        })
+        .collect();
+    let assay_attribute =
+        writer.write_number_attribute("cu_pct", "segments", assays, Some(Precision::SignificantDigits(3)))?;
+
+    Ok(Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Drillhole DH-001".to_string(),
+        description: "Sample drillhole trace with a copper assay per interval.".to_string(),
+        geometry: Geometry::LineSet(geometry),
+        attributes: vec![assay_attribute],
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata: Default::default(),
+    })
+}
+
+/// Builds a small block model with a `"grade"` attribute that increases with depth, standing in
+/// for the sub-blocked models typical of a resource estimate. Writes its values array to
+/// `writer`.
+pub fn subblocked_model<W: IoWrite + Seek>(writer: &mut Writer<W>) -> Result<Element> {
+    const COUNT: [u32; 3] = [8, 8, 4];
+    let block_model = BlockModel {
+        count: COUNT,
+        size: [10.0, 10.0, 5.0],
+        origin: [-40.0, -40.0, -20.0],
+        axes: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        sparse: None,
+    };
+
+    let mut grades = Vec::with_capacity(block_model.block_count() as usize);
+    for _i in 0..COUNT[0] {
+        for _j in 0..COUNT[1] {
+            for k in 0..COUNT[2] {
+                // Grade rises linearly with depth (`k = 0` is the deepest level) and is the same
+                // across each level; values are pushed in `BlockIndex::to_linear` order.
+                let depth_fraction = (COUNT[2] - 1 - k) as f64 / (COUNT[2] - 1) as f64;
+                grades.push(0.5 + depth_fraction * 2.0);
+            }
+        }
+    }
+    let grade_attribute = writer.write_number_attribute("grade", "cells", grades, None)?;
+
+    Ok(Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Sub-blocked model".to_string(),
+        description: "Sample block model with grade increasing with depth.".to_string(),
+        geometry: Geometry::BlockModel(block_model),
+        attributes: vec![grade_attribute],
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata: Default::default(),
+    })
+}
+
+/// Builds a complete sample [`Project`] containing one of each generator above: a pit surface, a
+/// drillhole, and a sub-blocked model. The project's arrays are written to `writer`; call
+/// [`Writer::finish`] with the returned [`Project`] to produce a ready-to-open `.omf` file.
+pub fn sample_project<W: IoWrite + Seek>(writer: &mut Writer<W>) -> Result<Project> {
+    let mut project = Project::new("Sample project");
+    project.description = "Generated sample data for tutorials and demos.".to_string();
+    project.elements.push(pit_surface_with_benches(writer)?);
+    project.elements.push(drillhole_with_assays(writer)?);
+    project.elements.push(subblocked_model(writer)?);
+    Ok(project)
+}
@@ -0,0 +1,81 @@
+//! Convention for survey and as-built point clouds: point sets captured by field survey or laser
+//! scanning, which otherwise lose the accuracy, capture date, instrument, and per-point
+//! classification context they arrive with.
+//!
+//! Like [`crate::classification`], this is a metadata convention rather than a first-class type:
+//! [`new_survey_point_cloud`] builds a conforming [`Element`], and [`validate_survey_point_cloud`]
+//! checks that an element (however it was built) still conforms.
+
+use crate::attribute::{Attribute, AttributeData};
+use crate::geometry::{Geometry, PointSet};
+use crate::project::Element;
+
+/// The element metadata key recording the survey instrument's rated accuracy, in metres.
+pub const ACCURACY_METADATA_KEY: &str = "survey_accuracy_metres";
+/// The element metadata key recording the capture date, as an ISO 8601 date (`YYYY-MM-DD`).
+pub const CAPTURE_DATE_METADATA_KEY: &str = "survey_capture_date";
+/// The element metadata key recording the name or model of the capturing instrument.
+pub const INSTRUMENT_METADATA_KEY: &str = "survey_instrument";
+/// The required name of the per-point classification category attribute (e.g. ground,
+/// vegetation, structure, noise).
+pub const CLASSIFICATION_ATTRIBUTE_NAME: &str = "classification";
+
+/// Builds a point-set [`Element`] conforming to the survey/as-built point cloud convention:
+/// required accuracy, capture date, and instrument metadata, plus a `classification` category
+/// attribute over `classification_indices_array` with the given category `names`.
+pub fn new_survey_point_cloud(
+    name: impl Into<String>,
+    vertices_array: impl Into<String>,
+    accuracy_metres: f64,
+    capture_date: impl Into<String>,
+    instrument: impl Into<String>,
+    classification_indices_array: impl Into<String>,
+    classification_names: Vec<String>,
+) -> Element {
+    let mut metadata = std::collections::BTreeMap::new();
+    metadata.insert(ACCURACY_METADATA_KEY.to_string(), serde_json::json!(accuracy_metres));
+    metadata.insert(CAPTURE_DATE_METADATA_KEY.to_string(), serde_json::json!(capture_date.into()));
+    metadata.insert(INSTRUMENT_METADATA_KEY.to_string(), serde_json::json!(instrument.into()));
+    Element {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.into(),
+        description: String::new(),
+        geometry: Geometry::PointSet(PointSet { vertices: vertices_array.into(), origin: [0.0; 3] }),
+        attributes: vec![Attribute {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: CLASSIFICATION_ATTRIBUTE_NAME.to_string(),
+            location: "vertices".to_string(),
+            data: AttributeData::Category {
+                values: classification_indices_array.into(),
+                names: classification_names,
+                descriptions: Vec::new(),
+            },
+            metadata: Default::default(),
+        }],
+        bounding_box: None,
+        coordinate_reference_system: None,
+        metadata,
+    }
+}
+
+/// Checks that `element` conforms to the survey/as-built point cloud convention, returning a
+/// description of the first problem found, if any.
+pub fn validate_survey_point_cloud(element: &Element) -> Option<String> {
+    if !matches!(element.geometry, Geometry::PointSet(_)) {
+        return Some("survey point cloud must have PointSet geometry".to_string());
+    }
+    for key in [ACCURACY_METADATA_KEY, CAPTURE_DATE_METADATA_KEY, INSTRUMENT_METADATA_KEY] {
+        if !element.metadata.contains_key(key) {
+            return Some(format!("survey point cloud is missing required metadata key \"{key}\""));
+        }
+    }
+    let has_classification = element.attributes.iter().any(|attribute| {
+        attribute.name == CLASSIFICATION_ATTRIBUTE_NAME && matches!(attribute.data, AttributeData::Category { .. })
+    });
+    if !has_classification {
+        return Some(format!(
+            "survey point cloud is missing a \"{CLASSIFICATION_ATTRIBUTE_NAME}\" category attribute"
+        ));
+    }
+    None
+}
@@ -0,0 +1,111 @@
+//! Joining an element's attributes with a chosen location into row-oriented output, for
+//! exporters (CSV, databases) that would otherwise have to manually zip several attribute
+//! columns together and keep them aligned.
+
+use std::io::{Read, Seek};
+
+use crate::attribute::AttributeData;
+use crate::file::Reader;
+use crate::project::Element;
+use crate::Result;
+
+/// One value in an [`AttributeRow`]. `Null` covers both a missing/out-of-range category index
+/// and a row past the end of a shorter column (see [`attribute_table`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Number(f64),
+    Category(String),
+    Boolean(bool),
+    Null,
+}
+
+/// One row of an [`AttributeTable`]: the location index, and one [`CellValue`] per column in the
+/// same order as [`AttributeTable::column_names`].
+#[derive(Debug, Clone)]
+pub struct AttributeRow {
+    pub location_index: usize,
+    pub values: Vec<CellValue>,
+}
+
+enum Column {
+    Number(Vec<f64>),
+    Category { indices: Vec<u32>, names: Vec<String> },
+    Boolean(Vec<u8>),
+}
+
+impl Column {
+    fn value_at(&self, row: usize) -> CellValue {
+        match self {
+            Column::Number(values) => values.get(row).map_or(CellValue::Null, |&v| CellValue::Number(v)),
+            Column::Category { indices, names } => indices
+                .get(row)
+                .and_then(|&i| names.get(i as usize))
+                .map_or(CellValue::Null, |name| CellValue::Category(name.clone())),
+            Column::Boolean(values) => values.get(row).map_or(CellValue::Null, |&v| CellValue::Boolean(v != 0)),
+        }
+    }
+}
+
+/// A row iterator over an element's attributes at a single location, built by
+/// [`attribute_table`].
+pub struct AttributeTable {
+    /// Names of the attributes included as columns, in the same order as each row's `values`.
+    pub column_names: Vec<String>,
+    row_count: usize,
+    next_row: usize,
+    columns: Vec<Column>,
+}
+
+impl Iterator for AttributeTable {
+    type Item = AttributeRow;
+
+    fn next(&mut self) -> Option<AttributeRow> {
+        if self.next_row >= self.row_count {
+            return None;
+        }
+        let row = AttributeRow {
+            location_index: self.next_row,
+            values: self.columns.iter().map(|column| column.value_at(self.next_row)).collect(),
+        };
+        self.next_row += 1;
+        Some(row)
+    }
+}
+
+/// Builds a row iterator over every attribute of `element` at `location` (e.g. `"vertices"`),
+/// reading each attribute's values from `reader`. Attributes at a different location are
+/// excluded; attributes whose data type isn't a fixed-width scalar (`Text`, `Texcoord`, or an
+/// unrecognized type) are excluded too, since they can't be joined into a uniform table.
+///
+/// The row count is taken from the longest included column; shorter columns report [`CellValue`]
+/// `Null` for rows past their end.
+pub fn attribute_table<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    element: &Element,
+    location: &str,
+) -> Result<AttributeTable> {
+    let mut column_names = Vec::new();
+    let mut columns = Vec::new();
+    let mut row_count = 0;
+    for attribute in &element.attributes {
+        if attribute.location != location {
+            continue;
+        }
+        let column = match &attribute.data {
+            AttributeData::Number { values } => Column::Number(reader.read_array_of(values)?),
+            AttributeData::Category { values, names, .. } => {
+                Column::Category { indices: reader.read_array_of(values)?, names: names.clone() }
+            }
+            AttributeData::Boolean { values } => Column::Boolean(reader.read_array_of(values)?),
+            AttributeData::Text { .. } | AttributeData::Texcoord { .. } | AttributeData::Unknown { .. } => continue,
+        };
+        row_count = row_count.max(match &column {
+            Column::Number(v) => v.len(),
+            Column::Category { indices, .. } => indices.len(),
+            Column::Boolean(v) => v.len(),
+        });
+        column_names.push(attribute.name.clone());
+        columns.push(column);
+    }
+    Ok(AttributeTable { column_names, row_count, next_row: 0, columns })
+}
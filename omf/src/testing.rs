@@ -0,0 +1,51 @@
+//! Helpers for producing intentionally invalid `.omf` files, for testing that readers (this
+//! crate's own, or downstream ones) fail gracefully instead of panicking or hanging.
+//!
+//! Not enabled by default: opt in with the `test-utils` feature. Nothing here should ever be
+//! used outside of tests.
+
+use std::io::{Seek, Write};
+
+use crate::Result;
+
+/// A single way a generated test file can be broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corruption {
+    /// `project.json` is missing entirely.
+    MissingProjectJson,
+    /// `project.json` contains invalid JSON syntax.
+    TruncatedProjectJson,
+    /// An element references an array name that isn't present in the file.
+    DanglingArrayReference,
+}
+
+/// Writes a minimal `.omf`-shaped zip file to `target` with the given corruption deliberately
+/// introduced, for exercising a reader's error paths.
+pub fn write_corrupt_file<W: Write + Seek>(target: W, corruption: Corruption) -> Result<()> {
+    let mut zip = zip::ZipWriter::new(target);
+    let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+    match corruption {
+        Corruption::MissingProjectJson => {
+            zip.start_file("readme.txt", options)?;
+            zip.write_all(b"intentionally missing project.json")?;
+        }
+        Corruption::TruncatedProjectJson => {
+            zip.start_file("project.json", options)?;
+            zip.write_all(b"{\"name\": \"broken")?;
+        }
+        Corruption::DanglingArrayReference => {
+            zip.start_file("project.json", options)?;
+            let json = serde_json::json!({
+                "name": "dangling reference test",
+                "elements": [{
+                    "name": "orphan",
+                    "geometry": {"type": "PointSet", "vertices": "does-not-exist", "origin": [0.0, 0.0, 0.0]},
+                    "attributes": [],
+                }],
+            });
+            zip.write_all(json.to_string().as_bytes())?;
+        }
+    }
+    zip.finish()?;
+    Ok(())
+}
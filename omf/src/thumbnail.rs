@@ -0,0 +1,46 @@
+//! Small pre-rendered PNG thumbnails attached to a project or an element, so file browsers and
+//! data portals can show a preview without decoding geometry.
+//!
+//! Stored as a metadata convention under [`THUMBNAIL_METADATA_KEY`], following the same pattern
+//! as [`crate::classification`] and [`crate::changelog`], except the value is a reference to a
+//! written array (see [`crate::file::Writer::write_element_thumbnail`] and
+//! [`crate::file::Writer::write_project_thumbnail`]) rather than inline JSON, since a
+//! PNG doesn't belong in a text document.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+pub const THUMBNAIL_METADATA_KEY: &str = "thumbnail";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// True if `bytes` starts with the PNG signature.
+pub fn is_png(bytes: &[u8]) -> bool {
+    bytes.starts_with(&PNG_SIGNATURE)
+}
+
+/// Records `array_name` as the thumbnail array for whichever metadata map is passed in (an
+/// [`crate::Element`]'s or a [`crate::Project`]'s).
+pub(crate) fn set(metadata: &mut BTreeMap<String, Value>, array_name: &str) {
+    metadata.insert(THUMBNAIL_METADATA_KEY.to_string(), Value::String(array_name.to_string()));
+}
+
+/// Returns the thumbnail array name recorded in `metadata`, if any.
+pub fn array_name(metadata: &BTreeMap<String, Value>) -> Option<&str> {
+    metadata.get(THUMBNAIL_METADATA_KEY)?.as_str()
+}
+
+/// Reads a PNG's width and height straight out of its `IHDR` chunk, without decoding any pixel
+/// data. `IHDR` is required to be the first chunk in a well-formed PNG, so this is reliable for
+/// anything a real encoder produced; used by [`crate::file::Reader::element_thumbnail_dimensions`]
+/// so a caller can check an image's size before deciding whether it's worth fetching and decoding
+/// the rest, without this crate needing an image codec dependency of its own.
+pub fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if !is_png(bytes) || bytes.len() < 24 || &bytes[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(bytes[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(bytes[20..24].try_into().ok()?);
+    Some((width, height))
+}
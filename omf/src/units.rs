@@ -0,0 +1,104 @@
+//! A controlled vocabulary for the unit strings recorded on attribute metadata (see
+//! [`crate::Attribute::metadata`], under [`UNITS_METADATA_KEY`]), plus a helper for converting
+//! values between two units in the same family.
+//!
+//! `Attribute` has no dedicated `units` field: like [`crate::attribute::NumberDisplayHint`], units
+//! are a metadata convention rather than a core schema field, since most consumers of an OMF file
+//! never need them and older files don't have them. This module just gives that convention a
+//! name, a recognized set of values, and a place for [`crate::file::Reader::project_with_warnings`]
+//! to flag ones it doesn't recognize instead of every caller inventing its own list.
+
+use std::collections::BTreeMap;
+
+use crate::Attribute;
+
+/// The attribute metadata key under which a unit string is stored.
+pub const UNITS_METADATA_KEY: &str = "units";
+
+/// A family of units that can be converted between each other. Values from different families
+/// (e.g. a length and a concentration) can't be meaningfully converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitFamily {
+    /// Distances and lengths.
+    Length,
+    /// Trace-element and assay concentrations.
+    Concentration,
+}
+
+/// One entry in the controlled vocabulary: a canonical unit string, its family, and the factor
+/// that converts a value in this unit to the family's base unit (meters for [`UnitFamily::Length`],
+/// parts-per-million for [`UnitFamily::Concentration`]).
+struct KnownUnit {
+    family: UnitFamily,
+    to_base: f64,
+}
+
+/// The controlled vocabulary of recognized unit strings, matched case-insensitively. Mining data
+/// exchange only needs a handful of these in practice; more can be added here as they come up
+/// rather than trying to enumerate every unit up front.
+fn known_units() -> &'static BTreeMap<&'static str, KnownUnit> {
+    use UnitFamily::{Concentration, Length};
+    static UNITS: std::sync::OnceLock<BTreeMap<&'static str, KnownUnit>> = std::sync::OnceLock::new();
+    UNITS.get_or_init(|| {
+        BTreeMap::from([
+            ("m", KnownUnit { family: Length, to_base: 1.0 }),
+            ("meters", KnownUnit { family: Length, to_base: 1.0 }),
+            ("metres", KnownUnit { family: Length, to_base: 1.0 }),
+            ("cm", KnownUnit { family: Length, to_base: 0.01 }),
+            ("mm", KnownUnit { family: Length, to_base: 0.001 }),
+            ("km", KnownUnit { family: Length, to_base: 1_000.0 }),
+            ("ft", KnownUnit { family: Length, to_base: 0.3048 }),
+            ("feet", KnownUnit { family: Length, to_base: 0.3048 }),
+            ("in", KnownUnit { family: Length, to_base: 0.0254 }),
+            ("inches", KnownUnit { family: Length, to_base: 0.0254 }),
+            ("ppm", KnownUnit { family: Concentration, to_base: 1.0 }),
+            ("g/t", KnownUnit { family: Concentration, to_base: 1.0 }),
+            ("%", KnownUnit { family: Concentration, to_base: 10_000.0 }),
+            ("pct", KnownUnit { family: Concentration, to_base: 10_000.0 }),
+            ("oz/t", KnownUnit { family: Concentration, to_base: 34.2857 }),
+        ])
+    })
+}
+
+/// True if `units` (matched case-insensitively) is in the controlled vocabulary.
+pub fn is_known_unit(units: &str) -> bool {
+    known_units().contains_key(units.to_lowercase().as_str())
+}
+
+/// Records `units` on `attribute`'s metadata.
+pub fn set_units(attribute: &mut Attribute, units: impl Into<String>) {
+    attribute.metadata.insert(UNITS_METADATA_KEY.to_string(), serde_json::Value::String(units.into()));
+}
+
+/// Reads the unit string recorded on `attribute`'s metadata, if any.
+pub fn units_of(attribute: &Attribute) -> Option<String> {
+    attribute.metadata.get(UNITS_METADATA_KEY).and_then(|value| value.as_str()).map(str::to_string)
+}
+
+/// Converts every value yielded by `values` from `from_units` to `to_units`, both matched
+/// case-insensitively against the controlled vocabulary. Returns an iterator adapter so callers
+/// can chain it directly onto a values array without an intermediate `Vec`.
+///
+/// Returns an error naming the problem if either unit isn't recognized, or if they belong to
+/// different [`UnitFamily`] variants (e.g. converting `"m"` to `"ppm"`), since there's no sound
+/// way to do that generically.
+pub fn convert_numbers<I: IntoIterator<Item = f64>>(
+    values: I,
+    from_units: &str,
+    to_units: &str,
+) -> Result<impl Iterator<Item = f64>, String> {
+    let table = known_units();
+    let from = table
+        .get(from_units.to_lowercase().as_str())
+        .ok_or_else(|| format!("\"{from_units}\" is not a recognized unit"))?;
+    let to = table
+        .get(to_units.to_lowercase().as_str())
+        .ok_or_else(|| format!("\"{to_units}\" is not a recognized unit"))?;
+    if from.family != to.family {
+        return Err(format!(
+            "can't convert \"{from_units}\" to \"{to_units}\": they're different kinds of unit"
+        ));
+    }
+    let factor = from.to_base / to.to_base;
+    Ok(values.into_iter().map(move |value| value * factor))
+}
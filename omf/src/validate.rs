@@ -0,0 +1,120 @@
+//! Optional, more expensive validation checks beyond basic schema/reference validity.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use crate::Result;
+
+/// A problem found by one of the topology checks below.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyProblem(pub String);
+
+/// True if a mesh with `vertex_count` vertices needs `wide_indices` (see
+/// [`crate::geometry::Surface::wide_indices`] and [`crate::geometry::LineSet::wide_indices`]) to
+/// index every vertex, i.e. it has more than [`u32::MAX`] of them.
+pub fn requires_wide_indices(vertex_count: usize) -> bool {
+    vertex_count > u32::MAX as usize
+}
+
+/// Checks that every edge of a triangulated surface is shared by exactly two triangles, which is
+/// the condition for a closed (watertight) surface. Edge direction is not checked here (see
+/// [`crate::geometry::check_winding`]). Reports edges that don't meet that rule.
+pub fn check_closed(triangles: &[[u32; 3]]) -> Vec<TopologyProblem> {
+    let mut edge_counts: HashMap<(u32, u32), i32> = HashMap::new();
+    for t in triangles {
+        for &(a, b) in &[(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            *edge_counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    edge_counts
+        .into_iter()
+        .filter(|&(_, count)| count != 2)
+        .map(|((a, b), count)| {
+            TopologyProblem(format!("edge ({a}, {b}) is used by {count} triangles, expected 2"))
+        })
+        .collect()
+}
+
+/// Checks that a triangulated surface is a valid solid: closed (see [`check_closed`]) and
+/// consistently wound (see [`crate::geometry::check_winding`]), the two conditions required for
+/// its inside/outside to be well defined. Used by [`crate::file::Writer::write_solid`] to reject
+/// a mesh before it's written with [`crate::geometry::Surface::closed`] set.
+pub fn check_solid(triangles: &[[u32; 3]]) -> Vec<TopologyProblem> {
+    let mut problems = check_closed(triangles);
+    let winding = crate::geometry::check_winding(triangles);
+    if winding.flipped > 0 {
+        problems.push(TopologyProblem(format!(
+            "{} triangles disagree with the majority winding direction, so the surface's \
+             inside/outside can't be determined consistently",
+            winding.flipped
+        )));
+    }
+    problems
+}
+
+/// Validates a `f64` array is entirely finite (no `NaN`/`inf`) by streaming it through a bounded
+/// buffer of `chunk_bytes` rather than reading the whole array into memory first. Suitable for
+/// checking arrays too large to comfortably hold twice over (once as raw bytes, once decoded).
+///
+/// Returns the index of the first non-finite value found, if any.
+pub fn check_finite_streaming(
+    mut source: impl Read,
+    chunk_bytes: usize,
+) -> Result<Option<usize>> {
+    let chunk_bytes = chunk_bytes.max(std::mem::size_of::<f64>());
+    let mut buffer = vec![0u8; chunk_bytes - chunk_bytes % std::mem::size_of::<f64>()];
+    let mut index = 0usize;
+    loop {
+        let read = read_full(&mut source, &mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        let values: &[f64] = bytemuck::cast_slice(&buffer[..read]);
+        for &value in values {
+            if !value.is_finite() {
+                return Ok(Some(index));
+            }
+            index += 1;
+        }
+    }
+    Ok(None)
+}
+
+fn read_full(source: &mut impl Read, buffer: &mut [u8]) -> Result<usize> {
+    let mut total = 0;
+    while total < buffer.len() {
+        let read = source.read(&mut buffer[total..])?;
+        if read == 0 {
+            break;
+        }
+        total += read;
+    }
+    Ok(total)
+}
+
+/// Checks that a triangulated surface is manifold: every vertex's incident triangles form a
+/// single fan (or half-fan on a boundary), not multiple disconnected fans meeting only at that
+/// vertex. This is a coarser, cheaper approximation that only flags vertices used by an odd
+/// number of boundary edges, which non-manifold vertices commonly are.
+pub fn check_manifold(triangles: &[[u32; 3]]) -> Vec<TopologyProblem> {
+    let mut boundary_edge_count: HashMap<u32, i32> = HashMap::new();
+    let mut edge_counts: HashMap<(u32, u32), i32> = HashMap::new();
+    for t in triangles {
+        for &(a, b) in &[(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            *edge_counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    for (&(a, b), &count) in &edge_counts {
+        if count == 1 {
+            *boundary_edge_count.entry(a).or_insert(0) += 1;
+            *boundary_edge_count.entry(b).or_insert(0) += 1;
+        }
+    }
+    boundary_edge_count
+        .into_iter()
+        .filter(|&(_, count)| count % 2 != 0)
+        .map(|(vertex, _)| {
+            TopologyProblem(format!("vertex {vertex} has an odd number of boundary edges, likely non-manifold"))
+        })
+        .collect()
+}